@@ -0,0 +1,248 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, str::FromStr};
+
+use serde::Deserialize;
+use toml::{Table, Value};
+
+use crate::etc::{AocError, AocResult, Context, Part, Solution};
+
+/// Where the known answers are kept unless `--answers` says otherwise.
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+/// Known answers keyed by year, day and part, along with any the puzzle site
+/// turned down, stored as TOML like
+///
+/// ```toml
+/// [2023.day01]
+/// part1 = "54951"
+/// part2 = "55218"
+///
+/// [2023.day01.rejected]
+/// part2 = [{ answer = "55300", verdict = "too high" }]
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    known: BTreeMap<(u16, u8, Part), Solution>,
+    rejected: BTreeMap<(u16, u8, Part), Vec<(Solution, Verdict)>>,
+}
+
+/// What the site said about an answer it turned down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint either way.
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Verdict, String> {
+        match s {
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            other => Err(format!("Not a verdict: {}", other)),
+        }
+    }
+}
+
+/// The outcome of comparing a solver's answer with the stored one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    Mismatch { expected: Solution },
+    Unknown,
+}
+
+impl Answers {
+    /// Loads the answers at `path`, starting empty if the file doesn't exist.
+    pub fn load(path: impl AsRef<Path>) -> AocResult<Answers> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Answers::parse(&text)
+            .map_err(|e| AocError::parse(format!("{} is malformed: {}", path.display(), e)))
+    }
+
+    fn parse(text: &str) -> AocResult<Answers> {
+        let table: Table = text.parse().context("Not valid TOML")?;
+
+        let mut answers = Answers::default();
+        for (year, days) in &table {
+            let year: u16 = year.parse().context("Answer years must be numbers")?;
+            for (day, parts) in days.as_table().context("Expected a table per year")? {
+                let day: u8 = day
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse().ok())
+                    .context("Answer days must look like day01")?;
+                for (key, value) in parts.as_table().context("Expected a table per day")? {
+                    if key == "rejected" {
+                        answers.load_rejected(year, day, value)?;
+                        continue;
+                    }
+                    // Hand-written files may use bare integers rather than strings
+                    let answer = Solution::deserialize(value.clone())
+                        .context("Answers must be integers or strings")?;
+                    answers.known.insert((year, day, part_of(key)?), answer);
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    fn load_rejected(&mut self, year: u16, day: u8, parts: &Value) -> AocResult<()> {
+        for (key, rejected) in parts
+            .as_table()
+            .context("Expected a table of rejected answers")?
+        {
+            let rejected = rejected
+                .as_array()
+                .context("Expected a list of rejected answers")?
+                .iter()
+                .map(|entry| {
+                    let answer = entry
+                        .get("answer")
+                        .context("Rejected answers need an answer")?;
+                    let verdict = entry
+                        .get("verdict")
+                        .and_then(Value::as_str)
+                        .context("Rejected answers need a verdict")?;
+                    Ok((
+                        Solution::deserialize(answer.clone())
+                            .context("Answers must be integers or strings")?,
+                        verdict.parse().map_err(AocError::parse)?,
+                    ))
+                })
+                .collect::<AocResult<_>>()?;
+            self.rejected.insert((year, day, part_of(key)?), rejected);
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> AocResult<()> {
+        let mut table = Table::new();
+        for ((year, day, part), answer) in &self.known {
+            day_table(&mut table, *year, *day).insert(
+                part_key(*part).to_string(),
+                Value::String(answer.to_string()),
+            );
+        }
+        for ((year, day, part), rejected) in &self.rejected {
+            let entries = rejected
+                .iter()
+                .map(|(answer, verdict)| {
+                    let mut entry = Table::new();
+                    entry.insert("answer".to_string(), Value::String(answer.to_string()));
+                    entry.insert("verdict".to_string(), Value::String(verdict.to_string()));
+                    Value::Table(entry)
+                })
+                .collect();
+            day_table(&mut table, *year, *day)
+                .entry("rejected")
+                .or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut()
+                .unwrap()
+                .insert(part_key(*part).to_string(), Value::Array(entries));
+        }
+        let text = toml::to_string(&table).expect("A table of strings always serialises");
+        let path = path.as_ref();
+        fs::write(path, text).map_err(|source| AocError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Solution> {
+        self.known.get(&(year, day, part))
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &Solution) {
+        self.known.insert((year, day, part), answer.clone());
+    }
+
+    /// Notes an answer the site turned down, so it is never sent again.
+    pub fn reject(&mut self, year: u16, day: u8, part: Part, answer: &Solution, verdict: Verdict) {
+        self.rejected
+            .entry((year, day, part))
+            .or_default()
+            .push((answer.clone(), verdict));
+    }
+
+    /// The rejected answer that rules `answer` out, if there is one: either
+    /// the same answer, or one the site called too high or too low that
+    /// `answer` is no better than.
+    pub fn ruled_out(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Solution,
+    ) -> Option<&(Solution, Verdict)> {
+        self.rejected
+            .get(&(year, day, part))?
+            .iter()
+            .find(|(wrong, verdict)| {
+                let bound = wrong.as_i128().zip(answer.as_i128());
+                wrong == answer
+                    || match (verdict, bound) {
+                        (Verdict::TooHigh, Some((wrong, answer))) => answer >= wrong,
+                        (Verdict::TooLow, Some((wrong, answer))) => answer <= wrong,
+                        _ => false,
+                    }
+            })
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Solution) -> Check {
+        match self.get(year, day, part) {
+            None => Check::Unknown,
+            Some(expected) if expected == answer => Check::Match,
+            Some(expected) => Check::Mismatch {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+/// The table for one day, created if it isn't there yet.
+fn day_table(table: &mut Table, year: u16, day: u8) -> &mut Table {
+    table
+        .entry(year.to_string())
+        .or_insert_with(|| Value::Table(Table::new()))
+        .as_table_mut()
+        .unwrap()
+        .entry(format!("day{:02}", day))
+        .or_insert_with(|| Value::Table(Table::new()))
+        .as_table_mut()
+        .unwrap()
+}
+
+fn part_of(key: &str) -> AocResult<Part> {
+    match key {
+        "part1" => Ok(Part::One),
+        "part2" => Ok(Part::Two),
+        other => Err(AocError::parse(format!("Unknown part: {}", other))),
+    }
+}
+
+fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part1",
+        Part::Two => "part2",
+    }
+}
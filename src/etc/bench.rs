@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use super::registry::{Day, DayOutput, Part, Timings};
+use super::AocResult;
+
+/// Summary statistics over the samples of one phase.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        // Sample standard deviation, which is zero for a single run
+        let variance = if secs.len() > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (secs.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Per-phase statistics for a day, along with the answers from its last run.
+pub struct BenchReport {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+    pub output: DayOutput,
+}
+
+/// Runs a day once to warm up, then `runs` more times, collecting the
+/// timings of every phase.
+pub fn bench(day: &Day, input: &str, part: Option<Part>, runs: usize) -> AocResult<BenchReport> {
+    assert!(runs > 0, "Must benchmark at least one run");
+    let mut output = day.solve(input, part)?;
+
+    let mut samples: Vec<Timings> = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        output = day.solve(input, part)?;
+        samples.push(output.timings);
+    }
+
+    let phase = |select: fn(&Timings) -> Option<Duration>| {
+        let durations: Vec<Duration> = samples.iter().filter_map(select).collect();
+        Stats::from_samples(&durations)
+    };
+
+    Ok(BenchReport {
+        runs,
+        parse: phase(|t| Some(t.parse)).expect("Parsing always runs"),
+        part1: phase(|t| t.part1),
+        part2: phase(|t| t.part2),
+        total: phase(|t| Some(t.total())).expect("Every run has a total"),
+        output,
+    })
+}
@@ -0,0 +1,82 @@
+use std::time::{Duration, Instant};
+
+/// Wall-clock and CPU time spent on a piece of work. CPU time is `None` on
+/// platforms where it can't be read.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Usage {
+    pub wall: Duration,
+    pub cpu: Option<Duration>,
+}
+
+impl Usage {
+    /// Runs `work` on the current thread and measures it.
+    pub fn measure<R>(work: impl FnOnce() -> R) -> (R, Usage) {
+        let cpu_start = thread_cpu_time();
+        let wall_start = Instant::now();
+        let result = work();
+        let usage = Usage {
+            wall: wall_start.elapsed(),
+            cpu: elapsed_since(cpu_start, thread_cpu_time()),
+        };
+        (result, usage)
+    }
+}
+
+/// Measures everything the process does from now on, across all threads.
+pub struct ProcessTimer {
+    wall_start: Instant,
+    cpu_start: Option<Duration>,
+}
+
+impl ProcessTimer {
+    pub fn start() -> ProcessTimer {
+        ProcessTimer {
+            wall_start: Instant::now(),
+            cpu_start: process_cpu_time(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Usage {
+        Usage {
+            wall: self.wall_start.elapsed(),
+            cpu: elapsed_since(self.cpu_start, process_cpu_time()),
+        }
+    }
+}
+
+fn elapsed_since(start: Option<Duration>, end: Option<Duration>) -> Option<Duration> {
+    Some(end?.saturating_sub(start?))
+}
+
+fn thread_cpu_time() -> Option<Duration> {
+    cpu_clock(ClockKind::Thread)
+}
+
+fn process_cpu_time() -> Option<Duration> {
+    cpu_clock(ClockKind::Process)
+}
+
+enum ClockKind {
+    Thread,
+    Process,
+}
+
+#[cfg(unix)]
+fn cpu_clock(kind: ClockKind) -> Option<Duration> {
+    let clock = match kind {
+        ClockKind::Thread => libc::CLOCK_THREAD_CPUTIME_ID,
+        ClockKind::Process => libc::CLOCK_PROCESS_CPUTIME_ID,
+    };
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid, writable timespec for the duration of the call
+    let result = unsafe { libc::clock_gettime(clock, &mut time) };
+    (result == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+#[cfg(not(unix))]
+fn cpu_clock(_kind: ClockKind) -> Option<Duration> {
+    None
+}
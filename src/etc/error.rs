@@ -0,0 +1,144 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+    path::PathBuf,
+};
+
+/// Everything that can go wrong while loading or solving a puzzle.
+#[derive(Debug)]
+pub enum AocError {
+    /// A puzzle input couldn't be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The input didn't look the way the solver expected. Lines and columns
+    /// are 1-based, as an editor would show them.
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    /// The input parsed fine, but the solver couldn't find an answer in it.
+    Solve(String),
+    /// The solver panicked with this message.
+    Panic(String),
+    /// The puzzle site couldn't be reached or turned the request down.
+    Site(String),
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    pub fn parse(message: impl Into<String>) -> AocError {
+        AocError::Parse {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn solve(message: impl Into<String>) -> AocError {
+        AocError::Solve(message.into())
+    }
+
+    /// Records which line a parse error came from, unless it already knows.
+    pub fn at_line(self, line: usize) -> AocError {
+        match self {
+            AocError::Parse {
+                line: None,
+                column,
+                message,
+            } => AocError::Parse {
+                line: Some(line),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    /// Records which column a parse error came from, unless it already knows.
+    pub fn at_column(self, column: usize) -> AocError {
+        match self {
+            AocError::Parse {
+                line,
+                column: None,
+                message,
+            } => AocError::Parse {
+                line,
+                column: Some(column),
+                message,
+            },
+            other => other,
+        }
+    }
+
+    /// Moves a parse error found in a block of text that starts on line
+    /// `first_line` to its line in the whole input, or points it at the start
+    /// of the block if it doesn't know its line.
+    pub fn in_block(self, first_line: usize) -> AocError {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => AocError::Parse {
+                line: Some(line.map_or(first_line, |line| first_line + line - 1)),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => match (line, column) {
+                (Some(line), Some(column)) => {
+                    write!(f, "line {}, column {}: {}", line, column, message)
+                }
+                (Some(line), None) => write!(f, "line {}: {}", line, message),
+                (None, Some(column)) => write!(f, "column {}: {}", column, message),
+                (None, None) => write!(f, "{}", message),
+            },
+            AocError::Solve(message) => write!(f, "{}", message),
+            AocError::Panic(message) => write!(f, "panicked: {}", message),
+            AocError::Site(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Turns a missing value or a foreign error into a parse error with a
+/// message, in the spirit of `expect` but without panicking.
+pub trait Context<T> {
+    fn context(self, message: impl Into<String>) -> AocResult<T>;
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context(self, message: impl Into<String>) -> AocResult<T> {
+        self.ok_or_else(|| AocError::parse(message))
+    }
+}
+
+impl<T, E: Display> Context<T> for Result<T, E> {
+    fn context(self, message: impl Into<String>) -> AocResult<T> {
+        self.map_err(|e| AocError::parse(format!("{} ({})", message.into(), e)))
+    }
+}
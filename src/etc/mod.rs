@@ -1,2 +1,13 @@
+pub mod answers;
+pub mod bench;
+pub mod clock;
+pub mod error;
+pub mod parallel;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod site;
 pub mod solution;
-pub use solution::Solution;
\ No newline at end of file
+pub use error::{AocError, AocResult, Context};
+pub use registry::{Day, Part};
+pub use solution::Solution;
@@ -0,0 +1,56 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Runs `work` over `items` on up to `jobs` threads, handing each result to
+/// `handle` on the calling thread in the original order of `items`, as soon
+/// as it and everything before it have finished.
+pub fn run_ordered<T, R>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut handle: impl FnMut(R),
+) where
+    T: Sync,
+    R: Send,
+{
+    if jobs <= 1 {
+        items.iter().map(&work).for_each(handle);
+        return;
+    }
+
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next_item, work) = (&next_item, &work);
+            scope.spawn(move || loop {
+                let index = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if sender.send((index, work(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Hold back results that finish early until their turn comes
+        let mut pending = BTreeMap::new();
+        let mut next_out = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_out) {
+                handle(result);
+                next_out += 1;
+            }
+        }
+    });
+}
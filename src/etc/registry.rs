@@ -0,0 +1,140 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crate::etc::{AocError, AocResult, Solution};
+
+/// One half of a day's puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// How long each phase of a run took, with `None` for parts that were skipped.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+/// The answers of a run, with `None` for any part that was not asked for.
+#[derive(Clone, Debug)]
+pub struct DayOutput {
+    pub part1: Option<Solution>,
+    pub part2: Option<Solution>,
+    pub timings: Timings,
+}
+
+/// Everything the runner needs to know about a single day's solver.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Solves the given puzzle input, restricted to one part if requested.
+    pub run: fn(&str, Option<Part>) -> AocResult<DayOutput>,
+}
+
+impl Day {
+    /// Solves `input`, restricted to one part if asked.
+    pub fn solve(&self, input: &str, part: Option<Part>) -> AocResult<DayOutput> {
+        (self.run)(input, part)
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        input_path(self.year, self.day)
+    }
+}
+
+/// Where a day's puzzle input is kept, whether or not it has a solver yet.
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{}/day{:02}.txt", year, day))
+}
+
+/// Parses the input once and feeds it to whichever parts were requested,
+/// timing each phase separately. A panicking solver is reported as an
+/// error rather than taking the whole run down with it.
+pub fn run_day<'a, P>(
+    input: &'a str,
+    part: Option<Part>,
+    parse: impl FnOnce(&'a str) -> AocResult<P>,
+    part1: impl FnOnce(&P) -> AocResult<Solution>,
+    part2: impl FnOnce(&P) -> AocResult<Solution>,
+) -> AocResult<DayOutput> {
+    let run = || {
+        let mut timings = Timings::default();
+
+        let time = Instant::now();
+        let parsed = parse(input)?;
+        timings.parse = time.elapsed();
+
+        let sol1 = if part != Some(Part::Two) {
+            let time = Instant::now();
+            let sol = part1(&parsed)?;
+            timings.part1 = Some(time.elapsed());
+            Some(sol)
+        } else {
+            None
+        };
+        let sol2 = if part != Some(Part::One) {
+            let time = Instant::now();
+            let sol = part2(&parsed)?;
+            timings.part2 = Some(time.elapsed());
+            Some(sol)
+        } else {
+            None
+        };
+
+        Ok(DayOutput {
+            part1: sol1,
+            part2: sol2,
+            timings,
+        })
+    };
+
+    panic::catch_unwind(AssertUnwindSafe(run)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_string());
+        Err(AocError::Panic(message))
+    })
+}
+
+/// Declares a year's day modules and builds its `DAYS` table from them, so
+/// adding a day only needs its module and one line in the invocation. Each
+/// module provides `TITLE`, `parse`, `part1` and `part2`, each returning an
+/// `AocResult`.
+#[macro_export]
+macro_rules! register_days {
+    (year: $year:literal; $($day:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const DAYS: &[$crate::etc::registry::Day] = &[
+            $($crate::etc::registry::Day {
+                year: $year,
+                day: $day,
+                title: $module::TITLE,
+                run: |input, part| {
+                    $crate::etc::registry::run_day(
+                        input,
+                        part,
+                        $module::parse,
+                        |parsed| $module::part1(parsed),
+                        |parsed| $module::part2(parsed),
+                    )
+                },
+            },)*
+        ];
+    };
+}
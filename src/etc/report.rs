@@ -0,0 +1,168 @@
+use std::{str::FromStr, time::Duration};
+
+use serde_json::{json, Value};
+
+use super::clock::Usage;
+use super::registry::{Day, DayOutput, Timings};
+use super::{AocError, Solution};
+
+/// How the runner prints its results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// The human-readable banner and bullet output.
+    Table,
+    /// A single JSON array with one object per day.
+    Json,
+    /// A header line followed by one row per day.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!(
+                "Not a valid format: {}, expected json, csv or table",
+                other
+            )),
+        }
+    }
+}
+
+/// Whether a day's answers were checked, and how that went.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The day ran but its answers were not checked.
+    Ok,
+    /// Every answer matched the stored one.
+    Verified,
+    /// At least one answer differed from the stored one.
+    Mismatch,
+    /// Nothing differed, but some answers had nothing stored to compare with
+    /// or a part is unsolved.
+    Unverified,
+    /// The input couldn't be read or the solver failed.
+    Error,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Verified => "verified",
+            Status::Mismatch => "mismatch",
+            Status::Unverified => "unverified",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// One day's results in a form that can be written out as JSON or CSV.
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub part1: Option<Solution>,
+    pub part2: Option<Solution>,
+    /// How long each phase took, or `None` if the day failed.
+    pub timings: Option<Timings>,
+    pub usage: Usage,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(day: &Day, output: DayOutput, usage: Usage, status: Status) -> Record {
+        Record {
+            year: day.year,
+            day: day.day,
+            title: day.title,
+            part1: output.part1,
+            part2: output.part2,
+            timings: Some(output.timings),
+            usage,
+            status,
+            error: None,
+        }
+    }
+
+    pub fn failed(day: &Day, usage: Usage, error: &AocError) -> Record {
+        Record {
+            year: day.year,
+            day: day.day,
+            title: day.title,
+            part1: None,
+            part2: None,
+            timings: None,
+            usage,
+            status: Status::Error,
+            error: Some(error.to_string()),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos() as u64);
+        let phase = |select: fn(&Timings) -> Option<Duration>| {
+            nanos(self.timings.as_ref().and_then(select))
+        };
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "title": self.title,
+            "part1": self.part1,
+            "part2": self.part2,
+            "elapsed_ns": {
+                "parse": phase(|t| Some(t.parse)),
+                "part1": phase(|t| t.part1),
+                "part2": phase(|t| t.part2),
+                "total": phase(|t| Some(t.total())),
+            },
+            "wall_ns": nanos(Some(self.usage.wall)),
+            "cpu_ns": nanos(self.usage.cpu),
+            "status": self.status.as_str(),
+            "error": self.error,
+        })
+    }
+
+    pub fn to_csv(&self) -> String {
+        let answer = |s: &Option<Solution>| {
+            s.as_ref()
+                .map_or(String::new(), |s| csv_field(&s.to_string()))
+        };
+        let nanos = |d: Option<Duration>| d.map_or(String::new(), |d| d.as_nanos().to_string());
+        let phase = |select: fn(&Timings) -> Option<Duration>| {
+            nanos(self.timings.as_ref().and_then(select))
+        };
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            csv_field(self.title),
+            answer(&self.part1),
+            answer(&self.part2),
+            phase(|t| Some(t.parse)),
+            phase(|t| t.part1),
+            phase(|t| t.part2),
+            phase(|t| Some(t.total())),
+            nanos(Some(self.usage.wall)),
+            nanos(self.usage.cpu),
+            self.status.as_str().to_string(),
+            self.error.as_deref().map_or(String::new(), csv_field),
+        ]
+        .join(",")
+    }
+}
+
+pub const CSV_HEADER: &str =
+    "year,day,title,part1,part2,parse_ns,part1_ns,part2_ns,total_ns,wall_ns,cpu_ns,status,error";
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
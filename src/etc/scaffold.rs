@@ -0,0 +1,197 @@
+//! Sets up a new day: its module, its line in the year's `register_days!`
+//! block, and a place for the examples from its puzzle text.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::etc::{AocError, AocResult};
+
+const MODULE: &str = r#"use crate::{etc::AocResult, Solution};
+
+pub const TITLE: &str = {title};
+
+pub fn parse(input: &str) -> AocResult<Vec<&str>> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(_lines: &[&str]) -> AocResult<Solution> {
+    Ok(Solution::Unsolved("not solved yet".to_string()))
+}
+
+pub fn part2(_lines: &[&str]) -> AocResult<Solution> {
+    Ok(Solution::Unsolved("not solved yet".to_string()))
+}
+"#;
+
+const EXAMPLE_ANSWERS: &str = r#"# The answers to each example in this directory, keyed by file name, like
+# part1 = "142". Examples are only checked for the parts listed.
+[example]
+"#;
+
+/// Adds day `day` of `year` to the source tree at `root`, registering the year
+/// too if it is new, and returns the files it wrote. Nothing that already
+/// exists is overwritten, and if any step fails the tree is left as it was.
+pub fn new_day(root: &Path, year: u16, day: u8, title: &str) -> AocResult<Vec<PathBuf>> {
+    let year_dir = root.join("src/years").join(format!("y{}", year));
+    let days_mod = year_dir.join("days/mod.rs");
+    let new_year = !days_mod.exists();
+    let module = year_dir.join(format!("days/day{:02}.rs", day));
+    let examples = root.join(format!("examples/{}/day{:02}", year, day));
+    let tests = root.join("tests/examples.rs");
+
+    // Every file is worked out before anything is written, so a registry laid
+    // out differently stops the scaffolding before it leaves files behind. The
+    // title goes into a string literal, so it is escaped the way Rust would.
+    let source = MODULE.replace("{title}", &format!("{:?}", title));
+    let mut created = vec![
+        (module.clone(), source),
+        (examples.join("answers.toml"), EXAMPLE_ANSWERS.to_string()),
+        (examples.join("example.txt"), String::new()),
+    ];
+    let mut edited = Vec::new();
+    let mut written = vec![module, examples];
+    if new_year {
+        let block = format!("crate::register_days! {{\n    year: {};\n}}\n", year);
+        let registry = add_day(&block, day).expect("A new block always takes a day");
+        created.push((year_dir.join("mod.rs"), "pub mod days;\n".to_string()));
+        created.push((days_mod.clone(), registry));
+        let years_mod = root.join("src/years/mod.rs");
+        edited.push(edit(&years_mod, |text| add_year(text, year))?);
+        written.push(years_mod);
+    } else {
+        edited.push(edit(&days_mod, |text| add_day(text, day))?);
+    }
+    written.push(days_mod);
+    edited.push(edit(&tests, |text| add_example_test(text, year, day))?);
+    written.push(tests);
+
+    for (i, (path, contents)) in created.iter().enumerate() {
+        if let Err(error) = create(path, contents) {
+            remove(&created[..i]);
+            return Err(error);
+        }
+    }
+    for (path, contents) in &edited {
+        if let Err(source) = fs::write(path, contents) {
+            remove(&created);
+            return Err(AocError::Io {
+                path: path.to_path_buf(),
+                source,
+            });
+        }
+    }
+    Ok(written)
+}
+
+fn create(path: &Path, contents: &str) -> AocResult<()> {
+    let io_error = |source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(io_error)
+}
+
+/// Takes back files this run created, so that trying again can start afresh.
+fn remove(created: &[(PathBuf, String)]) {
+    for (path, _) in created {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// The text of `path` after `change`, which gives `None` if it can't find its
+/// way around the file.
+fn edit(path: &Path, change: impl FnOnce(&str) -> Option<String>) -> AocResult<(PathBuf, String)> {
+    let io_error = |source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let text = fs::read_to_string(path).map_err(io_error)?;
+    let changed = change(&text).ok_or_else(|| {
+        io_error(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not laid out the way the scaffolding expects",
+        ))
+    })?;
+    Ok((path.to_path_buf(), changed))
+}
+
+/// Adds `pub mod yYYYY;` and its `DAYS` to `src/years/mod.rs`.
+fn add_year(text: &str, year: u16) -> Option<String> {
+    let module = format!("y{}", year);
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let last_mod = lines.iter().rposition(|l| l.starts_with("pub mod y"))?;
+    lines.insert(last_mod + 1, format!("pub mod {};", module));
+
+    let table = lines
+        .iter_mut()
+        .find(|l| l.starts_with("pub const YEARS"))?;
+    let close = table.rfind("];")?;
+    table.insert_str(close, &format!(", {}::days::DAYS", module));
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds `NN => dayNN,` to a year's `register_days!` block, keeping the days
+/// in order.
+fn add_day(text: &str, day: u8) -> Option<String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    // Either before the first later day or at the end of the block
+    let index = lines.iter().position(|line| {
+        line.trim() == "}"
+            || line
+                .split_once("=>")
+                .and_then(|(number, _)| number.trim().parse::<u8>().ok())
+                .is_some_and(|number| number > day)
+    })?;
+    lines.insert(index, format!("    {:>2} => day{:02},", day, day));
+    // A new year's block gets a blank line after its header
+    if let Some(header) = lines.iter().position(|l| l.trim().starts_with("year:")) {
+        if !lines[header + 1].is_empty() {
+            lines.insert(header + 1, String::new());
+        }
+    }
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the year's `example_tests!` list in `tests/examples.rs`,
+/// starting a module for the year if it has no list yet.
+fn add_example_test(text: &str, year: u16, day: u8) -> Option<String> {
+    let entry = format!("day{:02}_examples: {},", day, day);
+    let header = format!("example_tests!({} =>", year);
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let Some(start) = lines.iter().position(|l| l.trim() == header) else {
+        return Some(format!(
+            "{}\n\nmod y{} {{\n    use super::check_examples;\n\n    {}\n        {}\n    );\n}}\n",
+            text.trim_end(),
+            year,
+            header,
+            entry
+        ));
+    };
+    let indent = format!(
+        "{}    ",
+        &lines[start][..lines[start].find("example").unwrap_or(0)]
+    );
+    let end = start + 1 + lines[start + 1..].iter().position(|l| l.trim() == ");")?;
+    let later = lines[start + 1..end].iter().position(|line| {
+        line.trim()
+            .rsplit_once(": ")
+            .and_then(|(_, number)| number.trim_end_matches(',').parse::<u8>().ok())
+            .is_some_and(|number| number > day)
+    });
+    lines.insert(
+        later.map_or(end, |i| start + 1 + i),
+        format!("{}{}", indent, entry),
+    );
+    Some(lines.join("\n") + "\n")
+}
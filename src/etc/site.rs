@@ -0,0 +1,538 @@
+//! Talking to adventofcode.com. Requests go through a [`Transport`], so tests
+//! can point a [`Site`] at a local server or answer for it themselves.
+
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::etc::{
+    answers::{Answers, Verdict},
+    AocError, AocResult, Part, Solution,
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The shortest gap the runner leaves between two requests to the site.
+pub const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Where the time of the next allowed request is kept, so separate runs share
+/// the rate limit.
+pub const THROTTLE_STAMP: &str = "input/.last-request";
+
+/// Where the end of the wait after a wrong answer is kept. It only holds back
+/// answers, so inputs can still be fetched in the meantime.
+pub const COOLDOWN_STAMP: &str = "input/.answer-cooldown";
+
+const USER_AGENT: &str = "github.com/trilson/aoc23 by trilson";
+
+/// The status and body of a response from the site.
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests to the site on behalf of a [`Site`].
+pub trait Transport {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> AocResult<Response>;
+
+    /// Posts `form` URL-encoded, as a browser would.
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> AocResult<Response>;
+}
+
+/// Sends requests over the network.
+pub struct HttpTransport {
+    agent: ureq::Agent,
+}
+
+impl HttpTransport {
+    pub fn new() -> HttpTransport {
+        HttpTransport {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl Default for HttpTransport {
+    fn default() -> HttpTransport {
+        HttpTransport::new()
+    }
+}
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> AocResult<Response> {
+        let mut request = self.agent.get(url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+        read_response(url, request.call())
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> AocResult<Response> {
+        let mut request = self.agent.post(url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+        read_response(url, request.send_form(form))
+    }
+}
+
+fn read_response(url: &str, result: Result<ureq::Response, ureq::Error>) -> AocResult<Response> {
+    // Error statuses still carry a body worth reading
+    let response = match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => return Err(AocError::Site(format!("Could not reach {}: {}", url, e))),
+    };
+    let status = response.status();
+    let body = response
+        .into_string()
+        .map_err(|e| AocError::Site(format!("Could not read the response: {}", e)))?;
+    Ok(Response { status, body })
+}
+
+/// Keeps requests at least `interval` apart, even across runs, by noting in a
+/// stamp file when the next one may go. Longer waits can be asked for with
+/// [`hold_off`](Throttle::hold_off), and are reported rather than slept
+/// through.
+pub struct Throttle {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(stamp: impl Into<PathBuf>, interval: Duration) -> Throttle {
+        Throttle {
+            stamp: stamp.into(),
+            interval,
+        }
+    }
+
+    /// A throttle with no interval of its own, which only holds requests back
+    /// for as long as the site asks, like the cooldown after a wrong answer.
+    pub fn cooldown(stamp: impl Into<PathBuf>) -> Throttle {
+        Throttle::new(stamp, Duration::ZERO)
+    }
+
+    /// How long until the next request may go.
+    pub fn remaining(&self) -> Duration {
+        fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+            .and_then(|next| next.duration_since(SystemTime::now()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Holds off every request for at least `wait`.
+    pub fn hold_off(&self, wait: Duration) -> AocResult<()> {
+        self.mark(SystemTime::now() + wait.max(self.remaining()))
+    }
+
+    /// Sleeps until the next request may go, then claims it. Waits longer
+    /// than the usual interval were asked for by the site, so they are
+    /// reported rather than slept through.
+    fn wait(&self) -> AocResult<()> {
+        let remaining = self.remaining();
+        if remaining > self.interval {
+            return Err(AocError::Site(format!(
+                "The site asked us to wait; try again in {}",
+                seconds(remaining)
+            )));
+        }
+        thread::sleep(remaining);
+        self.mark(SystemTime::now() + self.interval)
+    }
+
+    fn mark(&self, next: SystemTime) -> AocResult<()> {
+        let millis = next.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let io_error = |source| AocError::Io {
+            path: self.stamp.clone(),
+            source,
+        };
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        fs::write(&self.stamp, millis.as_millis().to_string()).map_err(io_error)
+    }
+}
+
+/// A logged-in view of the site. Every request goes through `throttle`, and
+/// answers also wait out `cooldown`.
+pub struct Site<T: Transport> {
+    transport: T,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+    cooldown: Throttle,
+}
+
+impl<T: Transport> Site<T> {
+    pub fn new(
+        transport: T,
+        base_url: impl Into<String>,
+        session: impl Into<String>,
+        throttle: Throttle,
+        cooldown: Throttle,
+    ) -> Site<T> {
+        Site {
+            transport,
+            base_url: base_url.into(),
+            session: session.into(),
+            throttle,
+            cooldown,
+        }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    fn get(&self, path: &str) -> AocResult<Response> {
+        self.throttle.wait()?;
+        let cookie = format!("session={}", self.session);
+        let headers = [("Cookie", cookie.as_str()), ("User-Agent", USER_AGENT)];
+        self.transport
+            .get(&format!("{}{}", self.base_url, path), &headers)
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> AocResult<Response> {
+        self.cooldown.wait()?;
+        self.throttle.wait()?;
+        let cookie = format!("session={}", self.session);
+        let headers = [("Cookie", cookie.as_str()), ("User-Agent", USER_AGENT)];
+        self.transport
+            .post(&format!("{}{}", self.base_url, path), &headers, form)
+    }
+
+    /// Downloads a day's puzzle input.
+    pub fn input(&self, year: u16, day: u8) -> AocResult<String> {
+        if SystemTime::now() < unlock_time(year, day) {
+            return Err(AocError::Site(format!(
+                "{} day {} hasn't unlocked yet",
+                year, day
+            )));
+        }
+        let response = self.get(&format!("/{}/day/{}/input", year, day))?;
+        match response.status {
+            200 if !response.body.is_empty() => Ok(response.body),
+            200 => Err(AocError::Site("The site sent an empty input".to_string())),
+            404 => Err(AocError::Site(format!(
+                "The site has no input for {} day {}",
+                year, day
+            ))),
+            status => Err(status_error(status)),
+        }
+    }
+
+    /// Sends an answer for one part and reads what the site made of it. Any
+    /// wait the site asks for holds back the next answer, but not inputs.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Solution) -> AocResult<Outcome> {
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+        let answer = answer.to_string();
+        let response = self.post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", level), ("answer", &answer)],
+        )?;
+        if response.status != 200 {
+            return Err(status_error(response.status));
+        }
+        let outcome = Outcome::parse(&response.body).ok_or_else(|| {
+            AocError::Site(format!(
+                "Could not make sense of the reply: {}",
+                page_text(&response.body).trim()
+            ))
+        })?;
+        match outcome {
+            Outcome::TooSoon(wait)
+            | Outcome::Incorrect {
+                wait: Some(wait), ..
+            } => self.cooldown.hold_off(wait)?,
+            _ => {}
+        }
+        Ok(outcome)
+    }
+}
+
+fn status_error(status: u16) -> AocError {
+    match status {
+        400 | 401 | 500 => AocError::Site(format!(
+            "The site turned down the session token (HTTP {}); log in again and update it",
+            status
+        )),
+        429 => AocError::Site("The site asked us to slow down; try again later".to_string()),
+        status => AocError::Site(format!("Unexpected HTTP {} from the site", status)),
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The answer was wrong, with how long to wait before the next one if
+    /// the site said.
+    Incorrect {
+        verdict: Verdict,
+        wait: Option<Duration>,
+    },
+    /// An answer went in too recently, and this much of the wait is left.
+    TooSoon(Duration),
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Reads the outcome from the page the site sends back.
+    pub fn parse(page: &str) -> Option<Outcome> {
+        let text = page_text(page);
+        if text.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if text.contains("That's not the right answer") {
+            let verdict = if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            };
+            let wait = retry_wait(&text);
+            Some(Outcome::Incorrect { verdict, wait })
+        } else if text.contains("You gave an answer too recently") {
+            Some(Outcome::TooSoon(
+                left_to_wait(&text).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if text.contains("Did you already complete it") {
+            Some(Outcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer"),
+            Outcome::Incorrect { verdict, wait } => {
+                write!(f, "That's not the right answer, it's {}", verdict)?;
+                match wait {
+                    Some(wait) => write!(f, "; wait {} before trying again", seconds(*wait)),
+                    None => Ok(()),
+                }
+            }
+            Outcome::TooSoon(wait) => {
+                write!(f, "An answer went in too recently; wait {}", seconds(*wait))
+            }
+            Outcome::AlreadySolved => write!(f, "That part is already solved"),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, where the site puts its reply, with
+/// the markup taken out.
+fn page_text(page: &str) -> String {
+    // Splitting at "<article" leaves the rest of that tag to skip
+    let (article, mut in_tag) = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or((page, false), |(article, _)| (article, true));
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Reads waits like "please wait one minute before trying again".
+fn retry_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" before trying again")?;
+    let (_, amount) = before.rsplit_once("wait ")?;
+    let (count, unit) = amount.split_once(' ')?;
+    let count: u64 = match count {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    let unit = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3_600,
+        _ => return None,
+    };
+    Some(Duration::from_secs(count * unit))
+}
+
+/// Reads waits like "You have 1m 38s left to wait".
+fn left_to_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, amount) = before.rsplit_once("You have ")?;
+    amount
+        .split_whitespace()
+        .map(|piece| {
+            let unit = match piece.chars().last()? {
+                's' => 1,
+                'm' => 60,
+                'h' => 3_600,
+                _ => return None,
+            };
+            let count = piece.strip_suffix(['s', 'm', 'h'])?;
+            count.parse::<u64>().ok().map(|count| count * unit)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn seconds(wait: Duration) -> String {
+    format!("{}s", wait.as_secs_f64().ceil())
+}
+
+/// Whether [`fetch_input`] had to go to the site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads a day's input to `path` unless it is already there. Inputs never
+/// change, so a file that exists is never fetched again.
+pub fn fetch_input<T: Transport>(
+    site: &Site<T>,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> AocResult<Fetched> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let input = site.input(year, day)?;
+    let io_error = |source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    // Written aside first so an interrupted fetch never leaves half an input
+    let partial = path.with_extension("part");
+    fs::write(&partial, input)
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(io_error)?;
+    Ok(Fetched::Downloaded)
+}
+
+/// Submits `answer` unless [`check_submission`] turns it down, then notes
+/// what the site said in the answers store.
+pub fn submit_answer<T: Transport>(
+    site: &Site<T>,
+    answers: &mut Answers,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Solution,
+) -> AocResult<Outcome> {
+    check_submission(answers, year, day, part, answer)?;
+    let outcome = site.submit(year, day, part, answer)?;
+    match outcome {
+        Outcome::Correct => answers.record(year, day, part, answer),
+        Outcome::Incorrect { verdict, .. } => answers.reject(year, day, part, answer, verdict),
+        Outcome::TooSoon(_) | Outcome::AlreadySolved => {}
+    }
+    Ok(outcome)
+}
+
+/// Makes sure `answer` is worth sending: it must be a real answer, and the
+/// site mustn't have turned it down before or given a too high or too low
+/// verdict that rules it out. Known answers don't stop it, as `--record` keeps
+/// answers the site has never seen.
+pub fn check_submission(
+    answers: &Answers,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Solution,
+) -> AocResult<()> {
+    if !answer.is_answer() {
+        return Err(AocError::Site(format!(
+            "There is no answer to submit ({})",
+            answer
+        )));
+    }
+    match answers.ruled_out(year, day, part, answer) {
+        Some((wrong, verdict)) if wrong == answer => Err(AocError::Site(format!(
+            "{} was already turned down as {}",
+            answer, verdict
+        ))),
+        Some((wrong, verdict)) => Err(AocError::Site(format!(
+            "{} is ruled out, since {} was {}",
+            answer, wrong, verdict
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// The session cookie from `AOC_SESSION`, or failing that from the file at
+/// [`session_path`].
+pub fn session_token() -> AocResult<String> {
+    let token = match env::var(SESSION_VAR) {
+        Ok(token) => token,
+        Err(_) => session_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .unwrap_or_default(),
+    };
+    let token = token.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    if token.is_empty() {
+        return Err(AocError::Site(format!(
+            "No session token: set {} or save it in {}",
+            SESSION_VAR,
+            session_path().map_or("~/.config/aoc/session".into(), |p| p.display().to_string())
+        )));
+    }
+    Ok(token.to_string())
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, or `~/.config/aoc/session`.
+pub fn session_path() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config.join("aoc").join("session"))
+}
+
+/// When a puzzle unlocks: midnight US Eastern, 05:00 UTC, on its day of
+/// December.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    // Days since 1970 by Howard Hinnant's days_from_civil, for a date in December
+    let era = (year / 400) as u64;
+    let year_of_era = (year % 400) as u64;
+    let day_of_year = 275 + day as u64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + 5 * 3_600)
+}
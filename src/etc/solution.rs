@@ -1,6 +1,23 @@
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use Solution::*;
 
+/// A puzzle answer. Integer answers compare, order and hash by value, so the
+/// variant a day happens to return doesn't matter: `I64(5) == U8(5)`. A `Str`
+/// that is written exactly as an integer would be counts as that integer, as
+/// it would to the puzzle site, so `Str("5") == I64(5)` but not `Str("05")`.
 #[derive(Clone, Debug)]
 pub enum Solution {
     I8(i8),
@@ -16,10 +33,219 @@ pub enum Solution {
     U128(u128),
     Usize(usize),
     Str(String),
+    /// Several lines of text, such as letters drawn in ASCII art.
+    Text(String),
+    /// A grid of characters, shown one row per line.
+    Grid(Vec<Vec<char>>),
+    /// The part has no puzzle to solve, like the second half of day 25.
+    None,
+    /// The part has a puzzle but the code doesn't solve it, for this reason.
+    Unsolved(String),
+}
+
+/// The value of a [`Solution`] independent of the type it was returned as.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Canonical<'a> {
+    /// Any integer that fits in an `i128`.
+    Int(i128),
+    /// A `u128` too large for an `i128`.
+    BigUint(u128),
+    /// Any text answer, with grids rendered one row per line.
+    Text(Cow<'a, str>),
+    Unsolved(&'a str),
+    None,
+}
+
+impl Solution {
+    pub fn canonical(&self) -> Canonical<'_> {
+        match self {
+            I8(x) => Canonical::Int(*x as i128),
+            I16(x) => Canonical::Int(*x as i128),
+            I32(x) => Canonical::Int(*x as i128),
+            I64(x) => Canonical::Int(*x as i128),
+            I128(x) => Canonical::Int(*x),
+            Isize(x) => Canonical::Int(*x as i128),
+            U8(x) => Canonical::Int(*x as i128),
+            U16(x) => Canonical::Int(*x as i128),
+            U32(x) => Canonical::Int(*x as i128),
+            U64(x) => Canonical::Int(*x as i128),
+            U128(x) => i128::try_from(*x).map_or(Canonical::BigUint(*x), Canonical::Int),
+            Usize(x) => Canonical::Int(*x as i128),
+            Str(x) => parse_int(x).unwrap_or(Canonical::Text(Cow::Borrowed(x))),
+            Text(x) => Canonical::Text(Cow::Borrowed(x)),
+            Grid(_) => Canonical::Text(Cow::Owned(self.to_string())),
+            None => Canonical::None,
+            Unsolved(reason) => Canonical::Unsolved(reason),
+        }
+    }
+
+    /// Whether this is an actual answer, as opposed to `None` or `Unsolved`.
+    pub fn is_answer(&self) -> bool {
+        !matches!(self, None | Unsolved(_))
+    }
+
+    /// The answer as an integer, if it is one and fits in an `i128`.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self.canonical() {
+            Canonical::Int(x) => Some(x),
+            _ => Option::None,
+        }
+    }
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Eq for Solution {}
+
+impl Hash for Solution {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state)
+    }
+}
+
+/// Integers sort by value and before any text answer, and real answers sort
+/// before unsolved parts.
+impl Ord for Solution {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical().cmp(&other.canonical())
+    }
+}
+
+impl PartialOrd for Solution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The integer `s` spells, if it is written exactly as `Display` would write
+/// it, so that text like `007` or `+7` stays text.
+fn parse_int(s: &str) -> Option<Canonical<'static>> {
+    if let Ok(x) = s.parse::<i128>() {
+        (x.to_string() == s).then_some(Canonical::Int(x))
+    } else {
+        let x = s.parse::<u128>().ok()?;
+        (x.to_string() == s).then_some(Canonical::BigUint(x))
+    }
+}
+
+/// Reads back an answer written with `Display`. Integers become the first of
+/// `I64`, `I128` or `U128` that holds them, and anything else is kept as text.
+/// Multi-line answers come back as `Text`, whether or not they were a `Grid`.
+impl FromStr for Solution {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Solution, Infallible> {
+        Ok(match parse_int(s) {
+            Some(Canonical::Int(x)) => i64::try_from(x).map_or(I128(x), I64),
+            Some(Canonical::BigUint(x)) => U128(x),
+            _ if s.contains('\n') => Text(s.to_owned()),
+            _ => Str(s.to_owned()),
+        })
+    }
+}
+
+/// Integers are written as numbers when they fit in 64 bits and as strings
+/// otherwise, as many JSON readers lose precision beyond that. Text stays a
+/// string, grids become an array of rows, `None` is null and `Unsolved` is
+/// `{"unsolved": reason}`.
+impl Serialize for Solution {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Str(x) | Text(x) => return serializer.serialize_str(x),
+            Grid(rows) => {
+                return serializer
+                    .collect_seq(rows.iter().map(|row| row.iter().collect::<String>()))
+            }
+            _ => {}
+        }
+        match self.canonical() {
+            Canonical::Int(x) => {
+                if let Ok(x) = i64::try_from(x) {
+                    serializer.serialize_i64(x)
+                } else if let Ok(x) = u64::try_from(x) {
+                    serializer.serialize_u64(x)
+                } else {
+                    serializer.collect_str(&x)
+                }
+            }
+            Canonical::BigUint(x) => serializer.collect_str(&x),
+            Canonical::Text(x) => serializer.serialize_str(&x),
+            Canonical::Unsolved(reason) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("unsolved", reason)?;
+                map.end()
+            }
+            Canonical::None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Solution {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Solution, D::Error> {
+        deserializer.deserialize_any(SolutionVisitor)
+    }
+}
+
+struct SolutionVisitor;
+
+impl<'de> Visitor<'de> for SolutionVisitor {
+    type Value = Solution;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("an integer, a string, a list of grid rows or null")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Solution, E> {
+        Ok(I64(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Solution, E> {
+        Ok(U64(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Solution, E> {
+        Ok(I128(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Solution, E> {
+        Ok(U128(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Solution, E> {
+        let Ok(solution) = v.parse();
+        Ok(solution)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Solution, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Solution, E> {
+        Ok(None)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Solution, A::Error> {
+        let mut rows = Vec::new();
+        while let Some(row) = seq.next_element::<String>()? {
+            rows.push(row.chars().collect());
+        }
+        Ok(Grid(rows))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Solution, A::Error> {
+        match map.next_entry::<String, String>()? {
+            Some((key, reason)) if key == "unsolved" => Ok(Unsolved(reason)),
+            _ => Err(de::Error::custom("expected {\"unsolved\": reason}")),
+        }
+    }
 }
 
 impl Display for Solution {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             I8(x) => x.fmt(f),
             I16(x) => x.fmt(f),
@@ -33,7 +259,18 @@ impl Display for Solution {
             U64(x) => x.fmt(f),
             U128(x) => x.fmt(f),
             Usize(x) => x.fmt(f),
-            Str(x) => x.fmt(f),
+            Str(x) | Text(x) => x.fmt(f),
+            Grid(rows) => {
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{}", row.iter().collect::<String>())?;
+                }
+                Ok(())
+            }
+            None => f.write_str("n/a"),
+            Unsolved(reason) => write!(f, "unsolved: {}", reason),
         }
     }
 }
@@ -67,3 +304,9 @@ impl From<&str> for Solution {
         Self::Str(sol.to_owned())
     }
 }
+
+impl From<Vec<Vec<char>>> for Solution {
+    fn from(rows: Vec<Vec<char>>) -> Self {
+        Self::Grid(rows)
+    }
+}
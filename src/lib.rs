@@ -0,0 +1,17 @@
+//! Advent of Code solutions organised by year and day, along with the
+//! plumbing shared by the `advent_of_code` runner: the day registry, input
+//! loading, timing, answer checking and the `Solution` type every day returns.
+//!
+//! ```no_run
+//! let day = advent_of_code::find_day(2023, 1).unwrap();
+//! let input = advent_of_code::utils::files::read_input(day.input_path()).unwrap();
+//! let output = day.solve(&input, None).unwrap();
+//! println!("{:?}", output.part1);
+//! ```
+
+pub mod etc;
+pub mod utils;
+pub mod years;
+
+pub use etc::{AocError, AocResult, Day, Part, Solution};
+pub use years::{all_days, find_day, latest_year};
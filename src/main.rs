@@ -1,73 +1,619 @@
-mod days;
-mod etc;
-mod utils;
-use etc::solution::Solution;
-use days::{day01, day02, day03, day04, day05,
-           day06, day07, day08, day09, day10,
-           day11, day12, day13, day14, day15,
-           day16, day17, day18, day19, day20,
-           day21, day22, day23, day24, day25};
+use advent_of_code::etc::answers::{self, Answers, Check};
+use advent_of_code::etc::bench::{self, BenchReport, Stats};
+use advent_of_code::etc::clock::{ProcessTimer, Usage};
+use advent_of_code::etc::parallel;
+use advent_of_code::etc::registry::{self, DayOutput, Timings};
+use advent_of_code::etc::report::{self, Format, Record, Status};
+use advent_of_code::etc::scaffold;
+use advent_of_code::etc::site::{self, HttpTransport, Site, Throttle};
+use advent_of_code::utils::files::read_input;
+use advent_of_code::{years, AocError, AocResult, Day, Part, Solution};
 use std::env;
-use std::time::Instant;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-pub type SolutionPair = (Solution, Solution);
+/// Numeric arguments from this value upwards are read as a year, not a day.
+const FIRST_YEAR: u16 = 2015;
+
+/// Number of timed runs per day for `--bench` when `--runs` isn't given.
+const DEFAULT_BENCH_RUNS: usize = 10;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        panic!("Please provide the day(s) to run as a command-line argument.");
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        panic!("Please provide the [year and] day(s) to run as command-line arguments, or --list.");
+    }
+    match args[0].as_str() {
+        "fetch" => return fetch_inputs(&args[1..]),
+        "submit" => return submit_answer(&args[1..]),
+        "new" => return new_day(&args[1..]),
+        _ => {}
+    }
+
+    let mut year = years::latest_year();
+    let mut selections: Vec<String> = Vec::new();
+    let mut input: Option<String> = None;
+    let mut part: Option<Part> = None;
+    let mut bench_runs: Option<usize> = None;
+    let mut bench_out: Option<String> = None;
+    let mut jobs: usize = 1;
+    let mut format = Format::Table;
+    let mut verify = false;
+    let mut record = false;
+    let mut answers_path = answers::DEFAULT_ANSWERS_PATH.to_string();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--list" | "-l" => {
+                list_days();
+                return;
+            }
+            "--input" | "-i" => {
+                input = Some(args.next().expect("--input needs a path, or - for stdin"));
+            }
+            "--part" | "-p" => part = Some(parse_part(args.next().as_deref())),
+            "--bench" | "-b" => {
+                bench_runs.get_or_insert(DEFAULT_BENCH_RUNS);
+            }
+            "--runs" => {
+                bench_runs = Some(
+                    args.next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|&n| n > 0)
+                        .expect("--runs needs a number of runs"),
+                );
+            }
+            "--bench-out" => {
+                bench_out = Some(args.next().expect("--bench-out needs a path"));
+            }
+            "--jobs" | "-j" => {
+                jobs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .expect("--jobs needs a number of threads");
+            }
+            "--format" | "-f" => {
+                let name = args
+                    .next()
+                    .expect("--format needs one of json, csv or table");
+                format = name.parse().unwrap_or_else(|e| panic!("{}", e));
+            }
+            "--verify" => verify = true,
+            "--record" => record = true,
+            "--answers" => {
+                answers_path = args.next().expect("--answers needs a path");
+            }
+            _ => match arg.parse::<u16>() {
+                Ok(value) if value >= FIRST_YEAR => year = value,
+                _ => selections.push(arg),
+            },
+        }
     }
 
-    let days: Vec<u8> = args[1..].iter()
-        .map(|x| x.parse().unwrap_or_else(|v| panic!("Not a valid day: {}", v)))
+    let days: Vec<u8> = selections
+        .iter()
+        .flat_map(|selection| parse_day_selection(selection, year))
         .collect();
-    let mut runtime = 0.0;
+    if days.is_empty() {
+        panic!("Please provide at least one day to run.");
+    }
+    if input.is_some() && days.len() != 1 {
+        panic!("--input can only be used when running a single day");
+    }
+    // Custom inputs are read up front so that only the solver is timed
+    let custom_input = input
+        .map(read_input)
+        .transpose()
+        .unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            process::exit(1);
+        });
+    let mut answers = Answers::load(&answers_path).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    let mut mismatches = 0;
+    let mut failures: Vec<String> = Vec::new();
+    let mut runtime = Duration::ZERO;
+    let mut records: Vec<Record> = Vec::new();
+
+    let solvers: Vec<&'static Day> = days.iter().map(|&day| get_day_solver(year, day)).collect();
+    let execute = |solver: &&'static Day| {
+        let loaded = match &custom_input {
+            Some(_) => Ok(None),
+            None => read_input(solver.input_path()).map(Some),
+        };
+        let (run, usage) = Usage::measure(|| {
+            let loaded = loaded?;
+            let text = loaded.as_deref().or(custom_input.as_deref()).unwrap_or_default();
+            Ok(match bench_runs {
+                Some(runs) => DayRun::Bench(Box::new(bench::bench(solver, text, part, runs)?)),
+                None => DayRun::Single(solver.solve(text, part)?),
+            })
+        });
+        (*solver, run, usage)
+    };
+
+    if format == Format::Csv {
+        println!("{}", report::CSV_HEADER);
+    }
+    let timer = ProcessTimer::start();
+    parallel::run_ordered(&solvers, jobs, execute, |(solver, run, usage)| {
+        let run: AocResult<DayRun> = run;
+        let run = match run {
+            Ok(run) => run,
+            Err(error) => {
+                if format == Format::Table {
+                    println!("\n=== {} Day {:02} ===", solver.year, solver.day);
+                    println!("  · Error: {}", error);
+                }
+                failures.push(format!("{} day{:02}: {}", solver.year, solver.day, error));
+                emit(format, Record::failed(solver, usage, &error), &mut records);
+                return;
+            }
+        };
+        let output = match run {
+            DayRun::Bench(report) => {
+                if format == Format::Table {
+                    print_bench_report(solver, &report);
+                    print_usage(&usage);
+                }
+                if let Some(path) = &bench_out {
+                    append_bench_csv(path, solver, &report);
+                }
+                runtime += report.total.mean;
+                // Structured output reports the mean of each phase
+                DayOutput {
+                    timings: Timings {
+                        parse: report.parse.mean,
+                        part1: report.part1.map(|s| s.mean),
+                        part2: report.part2.map(|s| s.mean),
+                    },
+                    ..report.output
+                }
+            }
+            DayRun::Single(output) => {
+                if format == Format::Table {
+                    print_output(solver, &output);
+                    print_usage(&usage);
+                }
+                runtime += output.timings.total();
+                output
+            }
+        };
+
+        let mut status = Status::Ok;
+        if verify {
+            let checks = verify_output(&answers, solver, &output);
+            if format == Format::Table {
+                print_checks(&checks);
+            }
+            mismatches += checks
+                .iter()
+                .filter(|(_, check, _)| matches!(check, Check::Mismatch { .. }))
+                .count();
+            status = status_of(&checks, &output);
+        }
+        if record {
+            for (part, answer) in parts_of(&output) {
+                answers.record(solver.year, solver.day, part, answer);
+            }
+        }
+
+        emit(format, Record::new(solver, output, usage, status), &mut records);
+    });
+    let total = timer.elapsed();
+
+    match format {
+        Format::Table => {
+            println!("Total runtime: {:.4} ms", as_ms(runtime));
+            print_usage(&total);
+        }
+        Format::Csv => {}
+        Format::Json => {
+            let json: Vec<_> = records.iter().map(Record::to_json).collect();
+            println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        }
+    }
+
+    if record {
+        if let Err(error) = answers.save(&answers_path) {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+        eprintln!("Recorded answers to {}", answers_path);
+    }
+    for failure in &failures {
+        eprintln!("{}", failure);
+    }
+    if mismatches > 0 {
+        eprintln!("{} answer(s) did not match {}", mismatches, answers_path);
+    }
+    if mismatches > 0 || !failures.is_empty() {
+        process::exit(1);
+    }
+}
+
+/// Prints CSV records straight away, while JSON ones are collected into a
+/// single array at the end.
+fn emit(format: Format, record: Record, records: &mut Vec<Record>) {
+    match format {
+        Format::Table => {}
+        Format::Csv => println!("{}", record.to_csv()),
+        Format::Json => records.push(record),
+    }
+}
+
+/// What a worker hands back for one day, depending on the run mode.
+enum DayRun {
+    Single(DayOutput),
+    Bench(Box<BenchReport>),
+}
+
+/// The parts that ran and produced a real answer, which are the only ones
+/// worth checking against or storing in the answers file.
+fn parts_of(output: &DayOutput) -> impl Iterator<Item = (Part, &Solution)> {
+    [(Part::One, &output.part1), (Part::Two, &output.part2)]
+        .into_iter()
+        .filter_map(|(part, answer)| answer.as_ref().map(|answer| (part, answer)))
+        .filter(|(_, answer)| answer.is_answer())
+}
+
+fn print_output(solver: &Day, output: &DayOutput) {
+    let timings = output.timings;
+
+    println!("\n=== {} Day {:02} ===", solver.year, solver.day);
+    println!("  · Parse: {:.4} ms", as_ms(timings.parse));
+    if let (Some(p1), Some(elapsed)) = (&output.part1, timings.part1) {
+        print_answer(1, p1, Some(elapsed));
+    }
+    if let (Some(p2), Some(elapsed)) = (&output.part2, timings.part2) {
+        print_answer(2, p2, Some(elapsed));
+    }
+    println!("  · Elapsed: {:.4} ms", as_ms(timings.total()));
+}
+
+/// Prints one part's answer and how long it took, calling out parts without a
+/// real answer and putting multi-line answers on their own indented lines.
+fn print_answer(number: u8, answer: &Solution, elapsed: Option<Duration>) {
+    let text = match answer {
+        Solution::None => "not applicable".to_string(),
+        Solution::Unsolved(reason) => format!("UNSOLVED ({})", reason),
+        answer => answer.to_string(),
+    };
+    let timing = elapsed.map_or(String::new(), |e| format!(" ({:.4} ms)", as_ms(e)));
+    if text.contains('\n') {
+        println!("  · Part {}:{}", number, timing);
+        for line in text.lines() {
+            println!("      {}", line);
+        }
+    } else {
+        println!("  · Part {}: {}{}", number, text, timing);
+    }
+}
+
+fn print_usage(usage: &Usage) {
+    match usage.cpu {
+        Some(cpu) => println!(
+            "  · Wall: {:.4} ms, CPU: {:.4} ms",
+            as_ms(usage.wall),
+            as_ms(cpu)
+        ),
+        None => println!("  · Wall: {:.4} ms", as_ms(usage.wall)),
+    }
+}
+
+/// Compares each part that ran with its stored answer.
+fn verify_output<'a>(
+    answers: &Answers,
+    solver: &Day,
+    output: &'a DayOutput,
+) -> Vec<(Part, Check, &'a Solution)> {
+    parts_of(output)
+        .map(|(part, answer)| {
+            (
+                part,
+                answers.check(solver.year, solver.day, part, answer),
+                answer,
+            )
+        })
+        .collect()
+}
+
+fn print_checks(checks: &[(Part, Check, &Solution)]) {
+    for (part, check, answer) in checks {
+        let number = if *part == Part::One { 1 } else { 2 };
+        match check {
+            Check::Match => println!("  · Part {} verified", number),
+            Check::Mismatch { expected } => {
+                println!(
+                    "  · Part {} MISMATCH: expected {}, got {}",
+                    number, expected, answer
+                )
+            }
+            Check::Unknown => println!("  · Part {} has no stored answer", number),
+        }
+    }
+}
+
+/// A day only counts as verified if every part that ran was checked, so an
+/// unsolved part leaves it unverified even though there was nothing to check.
+fn status_of(checks: &[(Part, Check, &Solution)], output: &DayOutput) -> Status {
+    let unsolved = [&output.part1, &output.part2]
+        .into_iter()
+        .any(|answer| matches!(answer, Some(Solution::Unsolved(_))));
+    if checks
+        .iter()
+        .any(|(_, check, _)| matches!(check, Check::Mismatch { .. }))
+    {
+        Status::Mismatch
+    } else if unsolved || checks.iter().any(|(_, check, _)| *check == Check::Unknown) {
+        Status::Unverified
+    } else {
+        Status::Verified
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+fn print_bench_report(solver: &Day, report: &BenchReport) {
+    let format_stats = |stats: &Stats| {
+        format!(
+            "min {:.4} ms, median {:.4} ms, mean {:.4} ms, stddev {:.4} ms",
+            as_ms(stats.min),
+            as_ms(stats.median),
+            as_ms(stats.mean),
+            as_ms(stats.stddev)
+        )
+    };
+
+    println!(
+        "\n=== {} Day {:02} ({} runs) ===",
+        solver.year, solver.day, report.runs
+    );
+    println!("  · Parse: {}", format_stats(&report.parse));
+    if let (Some(p1), Some(stats)) = (&report.output.part1, &report.part1) {
+        print_answer(1, p1, None);
+        println!("      {}", format_stats(stats));
+    }
+    if let (Some(p2), Some(stats)) = (&report.output.part2, &report.part2) {
+        print_answer(2, p2, None);
+        println!("      {}", format_stats(stats));
+    }
+    println!("  · Total: {}", format_stats(&report.total));
+}
+
+/// Appends one CSV row per phase to `path`, so repeated benchmarks build up
+/// a history that can be compared across commits.
+fn append_bench_csv(path: &str, solver: &Day, report: &BenchReport) {
+    let needs_header = !Path::new(path).exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .expect("Could not open benchmark output");
+    if needs_header {
+        writeln!(
+            file,
+            "timestamp,year,day,phase,runs,min_ns,median_ns,mean_ns,stddev_ns"
+        )
+        .expect("Could not write benchmark output");
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |t| t.as_secs());
+    let phases = [
+        ("parse", Some(&report.parse)),
+        ("part1", report.part1.as_ref()),
+        ("part2", report.part2.as_ref()),
+        ("total", Some(&report.total)),
+    ];
+    for (phase, stats) in phases {
+        if let Some(stats) = stats {
+            writeln!(
+                file,
+                "{},{},{},{},{},{},{},{},{}",
+                timestamp,
+                solver.year,
+                solver.day,
+                phase,
+                report.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )
+            .expect("Could not write benchmark output");
+        }
+    }
+}
+
+/// Expands `all`, single days, inclusive ranges like `1-10` and comma
+/// separated combinations of those into a list of days.
+fn parse_day_selection(selection: &str, year: u16) -> Vec<u8> {
+    if selection == "all" {
+        return years::all_days()
+            .filter(|d| d.year == year)
+            .map(|d| d.day)
+            .collect();
+    }
+
+    let parse_day = |day: &str| -> u8 {
+        day.trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("Not a valid day: {}", day))
+    };
 
+    selection
+        .split(',')
+        .flat_map(|item| match item.split_once('-') {
+            Some((from, to)) => (parse_day(from)..=parse_day(to)).collect::<Vec<_>>(),
+            None => vec![parse_day(item)],
+        })
+        .collect()
+}
+
+/// `fetch <year> <days>`: downloads whichever of the days' inputs aren't in
+/// `input/` yet. Here `all` means every puzzle of the year, solved or not.
+fn fetch_inputs(args: &[String]) {
+    let [year, selection] = args else {
+        panic!("Usage: fetch <year> <days>");
+    };
+    let year: u16 = year
+        .parse()
+        .unwrap_or_else(|_| panic!("Not a valid year: {}", year));
+    let days = match selection.as_str() {
+        "all" => (1..=25).collect(),
+        _ => parse_day_selection(selection, year),
+    };
+    if let Some(day) = days.iter().find(|day| !(1..=25).contains(*day)) {
+        panic!("Not a valid day: {}, puzzles run from 1 to 25", day);
+    }
+
+    // Only ask for the session token once something needs downloading
+    let mut client = None;
+    let mut failed = false;
     for day in days {
-        let func = get_day_solver(day);
-
-        let time = Instant::now();
-        let (p1, p2) = func();
-        let elapsed_ms = time.elapsed().as_nanos() as f64 / 1_000_000.0;
-        
-        println!("\n=== Day {:02} ===", day);
-        println!("  · Part 1: {}", p1);
-        println!("  · Part 2: {}", p2);
-        println!("  · Elapsed: {:.4} ms", elapsed_ms);
-
-        runtime += elapsed_ms;
-    }
-
-    println!("Total runtime: {:.4} ms", runtime);
-}
-
-fn get_day_solver(day: u8) -> fn() -> SolutionPair {
-    match day {
-         1 => day01::solve,
-         2 => day02::solve,
-         3 => day03::solve,
-         4 => day04::solve,
-         5 => day05::solve,
-         6 => day06::solve,
-         7 => day07::solve,
-         8 => day08::solve,
-         9 => day09::solve,
-        10 => day10::solve,
-        11 => day11::solve,
-        12 => day12::solve,
-        13 => day13::solve,
-        14 => day14::solve,
-        15 => day15::solve,
-        16 => day16::solve,
-        17 => day17::solve,
-        18 => day18::solve,
-        19 => day19::solve,
-        20 => day20::solve,
-        21 => day21::solve,
-        22 => day22::solve,
-        23 => day23::solve,
-        24 => day24::solve,
-        25 => day25::solve,
-         _ => unimplemented!(),
-    }
-}
\ No newline at end of file
+        let path = registry::input_path(year, day);
+        if path.exists() {
+            println!("{} day {:02}: already in {}", year, day, path.display());
+            continue;
+        }
+        let client = client.get_or_insert_with(connect);
+        match site::fetch_input(client, year, day, &path) {
+            Ok(_) => println!("{} day {:02}: saved to {}", year, day, path.display()),
+            Err(error) => {
+                eprintln!("{} day {:02}: {}", year, day, error);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// `submit [year] <day> <part>`: solves one part and sends its answer to the
+/// site, noting the outcome in the answers file.
+fn submit_answer(args: &[String]) {
+    let (year, day, part) = match args {
+        [year, day, part] => (
+            year.parse()
+                .unwrap_or_else(|_| panic!("Not a valid year: {}", year)),
+            day,
+            part,
+        ),
+        [day, part] => (years::latest_year(), day, part),
+        _ => panic!("Usage: submit [year] <day> <part>"),
+    };
+    let day: u8 = day
+        .parse()
+        .unwrap_or_else(|_| panic!("Not a valid day: {}", day));
+    let part = parse_part(Some(part));
+    let solver = get_day_solver(year, day);
+
+    let exit = |error: AocError| -> ! {
+        eprintln!("{} day{:02}: {}", year, day, error);
+        process::exit(1);
+    };
+    let input = read_input(solver.input_path()).unwrap_or_else(|e| exit(e));
+    let output = solver.solve(&input, Some(part)).unwrap_or_else(|e| exit(e));
+    let answer = match part {
+        Part::One => output.part1,
+        Part::Two => output.part2,
+    }
+    .expect("The part that was asked for always runs");
+
+    // Turn down answers that can't be right before asking for a session token
+    let mut answers = Answers::load(answers::DEFAULT_ANSWERS_PATH).unwrap_or_else(|e| exit(e));
+    site::check_submission(&answers, year, day, part, &answer).unwrap_or_else(|e| exit(e));
+    let number = match part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+    println!("{} day {:02} part {}: submitting {}", year, day, number, answer);
+    let outcome = site::submit_answer(&connect(), &mut answers, year, day, part, &answer)
+        .unwrap_or_else(|e| exit(e));
+    answers
+        .save(answers::DEFAULT_ANSWERS_PATH)
+        .unwrap_or_else(|e| exit(e));
+    println!("{}", outcome);
+}
+
+/// `new <year> <day> [title]`: adds the day's module, registers it and sets
+/// up its examples, then fetches its input if a session token is set up.
+fn new_day(args: &[String]) {
+    let (year, day, title) = match args {
+        [year, day] => (year, day, None),
+        [year, day, title] => (year, day, Some(title.as_str())),
+        _ => panic!("Usage: new <year> <day> [title]"),
+    };
+    let year: u16 = year
+        .parse()
+        .unwrap_or_else(|_| panic!("Not a valid year: {}", year));
+    let day: u8 = day
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .unwrap_or_else(|| panic!("Not a valid day: {}, puzzles run from 1 to 25", day));
+    let title = title.map_or(format!("Day {}", day), String::from);
+    if years::find_day(year, day).is_some() {
+        eprintln!("{} day {:02} already exists", year, day);
+        process::exit(1);
+    }
+
+    let written = scaffold::new_day(Path::new("."), year, day, &title).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    if site::session_token().is_ok() {
+        fetch_inputs(&[year.to_string(), day.to_string()]);
+    } else {
+        println!("Fetch the input with `fetch {} {}` once a session token is set up", year, day);
+    }
+}
+
+fn connect() -> Site<HttpTransport> {
+    let session = site::session_token().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    let throttle = Throttle::new(site::THROTTLE_STAMP, site::REQUEST_INTERVAL);
+    let cooldown = Throttle::cooldown(site::COOLDOWN_STAMP);
+    Site::new(
+        HttpTransport::new(),
+        site::BASE_URL,
+        session,
+        throttle,
+        cooldown,
+    )
+}
+
+fn parse_part(part: Option<&str>) -> Part {
+    match part {
+        Some("1") => Part::One,
+        Some("2") => Part::Two,
+        other => panic!("Not a valid part: {:?}, expected 1 or 2", other),
+    }
+}
+
+fn list_days() {
+    for day in years::all_days() {
+        println!("{} Day {:02}: {}", day.year, day.day, day.title);
+    }
+}
+
+fn get_day_solver(year: u16, day: u8) -> &'static Day {
+    years::find_day(year, day)
+        .unwrap_or_else(|| panic!("Day {} of {} is not implemented", day, year))
+}
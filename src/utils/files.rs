@@ -1,13 +1,206 @@
+//! Loading puzzle inputs, and [`Input`] views over one that hand out its
+//! lines, blank-line separated blocks or grid while keeping error line
+//! numbers pointing into the whole file.
+
 use std::{
+    fmt::{self, Debug, Formatter},
     fs::File,
-    io::{prelude::*, BufReader},
+    io::{self, prelude::*},
+    ops::Deref,
     path::Path,
+    str,
+};
+
+use crate::{
+    etc::{AocError, AocResult},
+    utils::{grid::Grid, parse::blocks},
 };
 
-pub fn lines_from_file(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
-    buf.lines()
-        .map(|l| l.expect("Could not parse line"))
+/// Reads a whole puzzle input into a single buffer that solvers borrow from.
+/// A path of `-` reads from stdin.
+pub fn read_input(source: impl AsRef<Path>) -> AocResult<InputBuffer> {
+    let source = source.as_ref();
+    let io_error = |source_err| AocError::Io {
+        path: source.to_path_buf(),
+        source: source_err,
+    };
+    if source == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(io_error)?;
+        return Ok(InputBuffer::from(input));
+    }
+    File::open(source).and_then(load).map_err(io_error)
+}
+
+#[cfg(feature = "mmap")]
+fn load(file: File) -> io::Result<InputBuffer> {
+    // Some platforms refuse to map an empty file
+    if file.metadata()?.len() == 0 {
+        return Ok(InputBuffer::from(String::new()));
+    }
+    // SAFETY: the map is only unsound if the file is changed while it's
+    // mapped, and nothing writes to puzzle inputs while a day runs.
+    let map = unsafe { memmap2::Mmap::map(&file)? };
+    str::from_utf8(&map).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(InputBuffer {
+        data: Data::Mapped(map),
+    })
+}
+
+#[cfg(not(feature = "mmap"))]
+fn load(mut file: File) -> io::Result<InputBuffer> {
+    let mut input = String::with_capacity(file.metadata().map_or(0, |m| m.len() as usize));
+    file.read_to_string(&mut input)?;
+    Ok(InputBuffer::from(input))
+}
+
+/// A puzzle input loaded once, either read into memory or, with the `mmap`
+/// feature, mapped straight from its file. It derefs to the whole text.
+pub struct InputBuffer {
+    data: Data,
+}
+
+enum Data {
+    Owned(String),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl InputBuffer {
+    pub fn as_str(&self) -> &str {
+        match &self.data {
+            Data::Owned(text) => text,
+            // SAFETY: the map was checked to be UTF-8 when it was loaded.
+            #[cfg(feature = "mmap")]
+            Data::Mapped(map) => unsafe { str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+impl Deref for InputBuffer {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for InputBuffer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<String> for InputBuffer {
+    fn from(text: String) -> InputBuffer {
+        InputBuffer {
+            data: Data::Owned(text),
+        }
+    }
+}
+
+impl Debug for InputBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Parses each line of `input` in turn, tagging any error with the number
+/// of the line that caused it.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse_line: impl FnMut(&'a str) -> AocResult<T>,
+) -> AocResult<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.at_line(i + 1)))
         .collect()
-}
\ No newline at end of file
+}
+
+/// A view over puzzle input that was loaded once, offering it as raw text,
+/// lines or blank-line separated blocks without copying it, or as a grid. Views
+/// remember which line of the whole input they start on, so errors found in
+/// a block still point at the right line.
+#[derive(Clone, Copy, Debug)]
+pub struct Input<'a> {
+    text: &'a str,
+    first_line: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Input<'a> {
+        Input {
+            text,
+            first_line: 1,
+        }
+    }
+
+    /// The text exactly as it was loaded.
+    pub fn raw(&self) -> &'a str {
+        self.text
+    }
+
+    /// The line of the whole input that this view starts on, counting from 1.
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.text.lines()
+    }
+
+    /// Like [`parse_lines`], with line numbers counted from the start of the
+    /// whole input.
+    pub fn parse_lines<T>(
+        &self,
+        parse_line: impl FnMut(&'a str) -> AocResult<T>,
+    ) -> AocResult<Vec<T>> {
+        parse_lines(self.text, parse_line).map_err(|e| self.locate(e))
+    }
+
+    /// The blank-line separated blocks of the input, such as day 13's
+    /// patterns.
+    pub fn blocks(&self) -> Vec<Input<'a>> {
+        blocks(self.text)
+            .into_iter()
+            .map(|(line, text)| Input {
+                text,
+                first_line: self.first_line + line - 1,
+            })
+            .collect()
+    }
+
+    /// Everything after the first `count` lines, such as the body of a block
+    /// under its heading.
+    pub fn skip_lines(&self, count: usize) -> Input<'a> {
+        let start = match count {
+            0 => 0,
+            _ => self
+                .text
+                .match_indices('\n')
+                .nth(count - 1)
+                .map_or(self.text.len(), |(i, _)| i + 1),
+        };
+        Input {
+            text: &self.text[start..],
+            first_line: self.first_line + count,
+        }
+    }
+
+    /// The lines as a grid of bytes, which must all be the same width.
+    pub fn grid(&self) -> AocResult<Grid<u8>> {
+        Grid::parse(self.text, Ok).map_err(|e| self.locate(e))
+    }
+
+    /// Moves an error found in this view to its line in the whole input.
+    pub fn locate(&self, error: AocError) -> AocError {
+        error.in_block(self.first_line)
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(text: &'a str) -> Input<'a> {
+        Input::new(text)
+    }
+}
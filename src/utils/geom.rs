@@ -0,0 +1,275 @@
+//! Points and compass directions for grid and 3D puzzles. Directions follow
+//! [`Grid`](super::grid::Grid) in having `y` grow downwards, so `Up` is
+//! `(0, -1)`.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Component-wise arithmetic for a point type, plus scaling by a number.
+macro_rules! point_ops {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scale: T) -> Self {
+                $point { $($axis: self.$axis * scale),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$axis += other.$axis;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$axis -= other.$axis;)+
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+/// Manhattan distance for the number types that have an `abs`.
+macro_rules! manhattan {
+    ($($t:ty),+) => {
+        $(
+            impl Point2<$t> {
+                /// The distance between two points moving only along the axes.
+                pub fn manhattan(self, other: Self) -> $t {
+                    (self.x - other.x).abs() + (self.y - other.y).abs()
+                }
+            }
+
+            impl Point3<$t> {
+                /// The distance between two points moving only along the axes.
+                pub fn manhattan(self, other: Self) -> $t {
+                    (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+                }
+            }
+        )+
+    };
+}
+
+manhattan!(i32, i64, isize, f64);
+
+/// One of the four directions sharing an edge with a cell, listed clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Reads the `U`, `R`, `D` and `L` that puzzles use for directions.
+    pub fn from_letter(letter: char) -> Option<Dir4> {
+        match letter {
+            'U' => Some(Dir4::Up),
+            'R' => Some(Dir4::Right),
+            'D' => Some(Dir4::Down),
+            'L' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    /// The `^`, `>`, `v` or `<` pointing this way, for drawing routes on a map.
+    pub fn arrow(self) -> char {
+        Dir8::from(self).arrow()
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step one cell in this direction.
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        Dir8::from(self).delta()
+    }
+}
+
+/// One of the eight directions sharing an edge or a corner with a cell,
+/// listed clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Turns an eighth of the way anticlockwise.
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns an eighth of the way clockwise.
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The character for drawing this direction on a map: `^`, `>`, `v` or
+    /// `<` along the axes, and `/` or `\` for the diagonal it runs along.
+    pub fn arrow(self) -> char {
+        match self {
+            Dir8::Up => '^',
+            Dir8::Right => '>',
+            Dir8::Down => 'v',
+            Dir8::Left => '<',
+            Dir8::UpRight | Dir8::DownLeft => '/',
+            Dir8::DownRight | Dir8::UpLeft => '\\',
+        }
+    }
+
+    /// Whether this is one of the four directions along an axis.
+    pub fn is_straight(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+
+    /// The step one cell in this direction.
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+/// Takes one step in a direction.
+impl<T: Add<Output = T> + From<i8>> Add<Dir4> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, dir: Dir4) -> Self {
+        self + dir.delta()
+    }
+}
+
+impl<T: Add<Output = T> + From<i8>> Add<Dir8> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, dir: Dir8) -> Self {
+        self + dir.delta()
+    }
+}
@@ -0,0 +1,255 @@
+//! A rectangular grid of cells, stored row by row and indexed by `(x, y)`
+//! with `x` counting columns from the left and `y` rows from the top.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::etc::{AocError, AocResult};
+
+/// The offsets of the cells sharing an edge with a cell.
+pub const NEIGHBOURS4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the cells sharing an edge or a corner with a cell.
+#[rustfmt::skip]
+pub const NEIGHBOURS8: [(i64, i64); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1,  0),          (1,  0),
+    (-1,  1), (0,  1), (1,  1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Reads one cell from each byte of `input`, a line per row. Every row
+    /// must be the same width, and errors from `cell` are tagged with where
+    /// it was.
+    pub fn parse(input: &str, mut cell: impl FnMut(u8) -> AocResult<T>) -> AocResult<Grid<T>> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            if *width.get_or_insert(line.len()) != line.len() {
+                return Err(
+                    AocError::parse("All rows of the grid must be the same length").at_line(y + 1),
+                );
+            }
+            for (x, &byte) in line.as_bytes().iter().enumerate() {
+                cells.push(cell(byte).map_err(|e| e.at_line(y + 1).at_column(x + 1))?);
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(AocError::parse("The grid is empty")),
+        }
+    }
+
+    /// Builds a grid from its cells, row by row.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(width, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        (0..self.width as i64).contains(&x) && (0..self.height as i64).contains(&y)
+    }
+
+    /// The cell at `(x, y)`, or `None` off the edge of the grid.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.contains(x, y)
+            .then(|| &self.cells[y as usize * self.width + x as usize])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.contains(x, y)
+            .then(|| &mut self.cells[y as usize * self.width + x as usize])
+    }
+
+    /// The cell at `(x, y)` on a plane tiled with copies of the grid.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "x = {} is off a grid {} wide",
+            x,
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    /// The positions of the cells sharing an edge with `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS4)
+    }
+
+    /// The positions of the cells sharing an edge or a corner with `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x as i64 + dx, y as i64 + dy))
+            .filter(|&(x, y)| self.contains(x, y))
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_cells(self.width, self.cells.iter().map(f).collect())
+    }
+
+    /// A `width` by `height` grid whose cells come from `f(x, y)`.
+    fn build<U>(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> U) -> Grid<U> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid::from_cells(width, cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns, mirroring the grid in its leading diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::<T>::build(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let height = self.height;
+        Grid::<T>::build(height, self.width, |x, y| self[(y, height - 1 - x)].clone())
+    }
+
+    /// Turns the grid a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        let width = self.width;
+        Grid::<T>::build(self.height, width, |x, y| self[(width - 1 - y, x)].clone())
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        Grid::<T>::build(width, self.height, |x, y| self[(width - 1 - x, y)].clone())
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        Grid::<T>::build(self.width, height, |x, y| self[(x, height - 1 - y)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width,
+            "x = {} is off a grid {} wide",
+            x,
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width,
+            "x = {} is off a grid {} wide",
+            x,
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Shows the grid a row per line, as it looked in the puzzle input.
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for &cell in row {
+                write!(f, "{}", cell.into())?;
+            }
+        }
+        Ok(())
+    }
+}
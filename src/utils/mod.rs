@@ -1 +1,5 @@
-pub mod files;
\ No newline at end of file
+pub mod files;
+pub mod geom;
+pub mod grid;
+pub mod parse;
+pub mod search;
@@ -0,0 +1,160 @@
+//! Helpers for pulling numbers, fields and blocks out of puzzle input, so a
+//! day can describe the shape of its input rather than splitting it by hand.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::etc::{AocError, AocResult, Context};
+
+/// Every integer in `text`, in order. A `-` straight before a number is read
+/// as its sign unless it follows a letter or digit, and everything else is
+/// skipped, so `"x=-3, y=4"` gives `[-3, 4]` but the range `"1-3"` gives
+/// `[1, 3]`.
+pub fn ints<T>(text: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = text.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let signed =
+            i > 0 && bytes[i - 1] == b'-' && (i == 1 || !bytes[i - 2].is_ascii_alphanumeric());
+        let start = if signed { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let number = &text[start..i];
+        found.push(parse_field(number).map_err(|e| e.at_column(start + 1))?);
+    }
+    Ok(found)
+}
+
+/// Like [`ints`], but there must be exactly `N` of them:
+/// `let [x, y, z] = ints_array::<i64, 3>("1,2,3")?;`
+pub fn ints_array<T, const N: usize>(text: &str) -> AocResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let found = ints(text)?;
+    let count = found.len();
+    found
+        .try_into()
+        .map_err(|_| AocError::parse(format!("Expected {} numbers, found {}", N, count)))
+}
+
+/// Splits `text` on `separator` into exactly as many fields as `T` has,
+/// parsing each one as its own type. Fields are trimmed and empty ones
+/// skipped, so runs of spaces count as one separator:
+/// `let (count, colour): (u32, String) = fields("3  blue", " ")?;`
+pub fn fields<T: FromFields>(text: &str, separator: &str) -> AocResult<T> {
+    let found: Vec<&str> = text
+        .split(separator)
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .collect();
+    if found.len() != T::COUNT {
+        return Err(AocError::parse(format!(
+            "Expected {} fields separated by {:?}, found {}",
+            T::COUNT,
+            separator,
+            found.len()
+        )));
+    }
+    T::from_fields(&found)
+}
+
+/// Parses each record in `text` delimited by `separator`, trimming them and
+/// skipping empty ones, like the comma-separated steps of 2023 day 15.
+pub fn records<'a, T>(
+    text: &'a str,
+    separator: &str,
+    parse_record: impl FnMut(&'a str) -> AocResult<T>,
+) -> AocResult<Vec<T>> {
+    text.split(separator)
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(parse_record)
+        .collect()
+}
+
+/// Splits `input` at blank lines, giving each block of text along with the
+/// line number it starts on.
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut found = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some((i + 1, offset)),
+            (true, Some((first_line, from))) => {
+                found.push((first_line, input[from..offset].trim_end()));
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, from)) = start {
+        found.push((first_line, input[from..].trim_end()));
+    }
+    found
+}
+
+/// Parses each blank-line separated block of `input` in turn, moving the
+/// line of any error to where it is in the whole input.
+pub fn parse_blocks<'a, T>(
+    input: &'a str,
+    mut parse_block: impl FnMut(&'a str) -> AocResult<T>,
+) -> AocResult<Vec<T>> {
+    blocks(input)
+        .into_iter()
+        .map(|(first_line, block)| parse_block(block).map_err(|e| e.in_block(first_line)))
+        .collect()
+}
+
+/// Types that can be built from a fixed number of text fields, which covers
+/// tuples of up to six `FromStr` types.
+pub trait FromFields: Sized {
+    const COUNT: usize;
+
+    /// Builds the value from exactly `COUNT` fields.
+    fn from_fields(fields: &[&str]) -> AocResult<Self>;
+}
+
+macro_rules! impl_from_fields {
+    ($count:literal => $($type_:ident $index:tt),+) => {
+        impl<$($type_),+> FromFields for ($($type_,)+)
+        where
+            $($type_: FromStr, $type_::Err: Display,)+
+        {
+            const COUNT: usize = $count;
+
+            fn from_fields(fields: &[&str]) -> AocResult<Self> {
+                Ok(($(parse_field::<$type_>(fields[$index])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1 => A 0);
+impl_from_fields!(2 => A 0, B 1);
+impl_from_fields!(3 => A 0, B 1, C 2);
+impl_from_fields!(4 => A 0, B 1, C 2, D 3);
+impl_from_fields!(5 => A 0, B 1, C 2, D 3, E 4);
+impl_from_fields!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+
+fn parse_field<T>(field: &str) -> AocResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field
+        .parse()
+        .context(format!("Could not parse {:?}", field))
+}
@@ -0,0 +1,164 @@
+//! Shortest paths over any state that can be hashed. Each search takes the
+//! start, a closure listing the states one step on from a state, and a test for
+//! the goal, so puzzle rules like day 17's run lengths live in the state and
+//! its successors rather than in the search.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cost of the cheapest way to a goal and the states along it, from the
+/// start to the goal inclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+}
+
+/// Breadth-first search, where every step costs one.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = Seen::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((steps, index)) = queue.pop_front() {
+        let state = seen.states[index].clone();
+        if is_goal(&state) {
+            return Some(seen.found(steps, index));
+        }
+        for next in successors(&state) {
+            if let Some(next) = seen.visit(next, index, steps + 1) {
+                queue.push_back((steps + 1, next));
+            }
+        }
+    }
+    None
+}
+
+/// Every state at most `limit` steps from the start, with how many steps it
+/// takes to get there.
+pub fn flood<S, I>(start: S, limit: usize, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, taken)) = queue.pop_front() {
+        if taken == limit {
+            continue;
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = steps.entry(next.clone()) {
+                entry.insert(taken + 1);
+                queue.push_back((next, taken + 1));
+            }
+        }
+    }
+    steps
+}
+
+/// Dijkstra's algorithm, for successors given with the cost of the step to
+/// them.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never guess more than the real cost of getting
+/// from a state to the goal, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut seen = Seen::new(start);
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let state = seen.states[index].clone();
+        // Skip states that were reached more cheaply since being queued
+        if seen.best[&state] != (cost, index) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(seen.found(cost, index));
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next) = seen.visit(next, index, next_cost) {
+                queue.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// The states a search has reached, each with the state it was reached from
+/// so the path can be traced back.
+struct Seen<S, C> {
+    states: Vec<S>,
+    parents: Vec<usize>,
+    best: HashMap<S, (C, usize)>,
+}
+
+impl<S: Hash + Eq + Clone, C: Copy + Ord + Default> Seen<S, C> {
+    fn new(start: S) -> Self {
+        Seen {
+            best: HashMap::from([(start.clone(), (C::default(), 0))]),
+            states: vec![start],
+            parents: vec![0],
+        }
+    }
+
+    /// Records reaching `state` from the state at `parent` for `cost`, giving
+    /// its index if that is the cheapest way there so far.
+    fn visit(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        let index = self.states.len();
+        match self.best.entry(state.clone()) {
+            Entry::Occupied(entry) if entry.get().0 <= cost => return None,
+            Entry::Occupied(mut entry) => {
+                entry.insert((cost, index));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((cost, index));
+            }
+        }
+        self.states.push(state);
+        self.parents.push(parent);
+        Some(index)
+    }
+
+    fn found(&self, cost: C, mut index: usize) -> Found<S, C> {
+        let mut path = vec![self.states[index].clone()];
+        while index != 0 {
+            index = self.parents[index];
+            path.push(self.states[index].clone());
+        }
+        path.reverse();
+        Found { cost, path }
+    }
+}
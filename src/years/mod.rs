@@ -0,0 +1,18 @@
+use crate::etc::Day;
+
+pub mod y2023;
+
+/// Every registered year, oldest first.
+pub const YEARS: &[&[Day]] = &[y2023::days::DAYS];
+
+pub fn all_days() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+pub fn latest_year() -> u16 {
+    all_days().map(|d| d.year).max().expect("At least one year must be registered")
+}
+
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    all_days().find(|d| d.year == year && d.day == day)
+}
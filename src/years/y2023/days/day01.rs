@@ -1,21 +1,26 @@
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{etc::AocResult, Solution};
 
-pub fn solve() -> SolutionPair {
-    let lines = lines_from_file("input/day01.txt");
+pub const TITLE: &str = "Trebuchet?!";
 
-    let sol1: u32 = solve_part(false, &lines);
-    let sol2: u32 = solve_part(true, &lines);
+pub fn parse(input: &str) -> AocResult<Vec<&str>> {
+    Ok(input.lines().collect())
+}
+
+pub fn part1(lines: &[&str]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part(false, lines)))
+}
 
-    (Solution::from(sol1), Solution::from(sol2))
+pub fn part2(lines: &[&str]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part(true, lines)))
 }
 
-fn solve_part(with_text: bool, lines: &Vec<String>) -> u32 {
+fn solve_part(with_text: bool, lines: &[&str]) -> u32 {
     let nums = vec![
         "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
     ];
 
     let mut sol = 0;
-    for line in lines {
+    for line in lines.iter().filter(|line| !line.is_empty()) {
         let mut i = 0;
         let mut j = line.len() - 1;
 
@@ -42,8 +47,8 @@ fn solve_part(with_text: bool, lines: &Vec<String>) -> u32 {
 }
 
 fn get_digit_from_str(input_str: &str, nums: &Vec<&str>, with_text: bool) -> Option<u32> {
-    let i_char = input_str.chars().nth(0).unwrap();
-    if i_char.is_digit(10) {
+    let i_char = input_str.chars().next()?;
+    if i_char.is_ascii_digit() {
         return i_char.to_digit(10);
     }
     if !with_text {
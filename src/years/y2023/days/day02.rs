@@ -0,0 +1,99 @@
+use crate::{
+    etc::{AocError, AocResult, Context},
+    utils::{
+        files::parse_lines,
+        parse::{fields, ints_array, records},
+    },
+    Solution,
+};
+use std::cmp::max;
+
+pub const TITLE: &str = "Cube Conundrum";
+
+#[derive(Debug)]
+struct Bag {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    bags: Vec<Bag>,
+}
+
+pub fn parse(input: &str) -> AocResult<Vec<Game>> {
+    extract_games(input)
+}
+
+pub fn part1(games: &[Game]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part1(games)))
+}
+
+pub fn part2(games: &[Game]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part2(games)))
+}
+
+fn solve_part1(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter_map(|game| {
+            if game
+                .bags
+                .iter()
+                .all(|bag| bag.red <= 12 && bag.green <= 13 && bag.blue <= 14)
+            {
+                Some(game.id)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn solve_part2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| {
+            let mut r = 0;
+            let mut b = 0;
+            let mut g = 0;
+            for bag in &game.bags {
+                r = max(r, bag.red);
+                b = max(b, bag.blue);
+                g = max(g, bag.green);
+            }
+            r * b * g
+        })
+        .sum()
+}
+
+fn extract_games(input: &str) -> AocResult<Vec<Game>> {
+    parse_lines(input, |line| {
+        let (game, bags) = line
+            .split_once(':')
+            .context("Expected a game like \"Game 1: ...\"")?;
+        let [id] = ints_array(game)?;
+        let bags = records(bags, ";", parse_bag)?;
+        Ok(Game { id, bags })
+    })
+}
+
+/// Reads one handful of cubes, like `3 blue, 4 red`.
+fn parse_bag(text: &str) -> AocResult<Bag> {
+    let mut bag = Bag {
+        red: 0,
+        green: 0,
+        blue: 0,
+    };
+    for (count, colour) in records(text, ",", |cubes| fields::<(u32, String)>(cubes, " "))? {
+        match colour.as_str() {
+            "red" => bag.red = count,
+            "green" => bag.green = count,
+            "blue" => bag.blue = count,
+            other => return Err(AocError::parse(format!("Unknown colour: {}", other))),
+        }
+    }
+    Ok(bag)
+}
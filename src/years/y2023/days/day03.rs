@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use crate::{etc::AocResult, utils::grid::Grid, Solution};
+
+pub const TITLE: &str = "Gear Ratios";
+
+#[derive(Debug, PartialEq, Clone)]
+enum PointType {
+    Symbol,
+    Number,
+    Star,
+}
+
+#[derive(Debug, Clone)]
+pub struct Point {
+    x: u16,
+    x_to: u16,
+    y: u16,
+    point_type: PointType,
+}
+
+pub struct Schematic {
+    grid: Grid<u8>,
+    points: Grid<Option<Point>>,
+}
+
+pub fn parse(input: &str) -> AocResult<Schematic> {
+    let grid = Grid::parse(input, Ok)?;
+    let points = generate_points(&grid);
+    Ok(Schematic { grid, points })
+}
+
+pub fn part1(schematic: &Schematic) -> AocResult<Solution> {
+    Ok(Solution::from(
+        sum_engine_parts(&schematic.points, &schematic.grid).0,
+    ))
+}
+
+pub fn part2(schematic: &Schematic) -> AocResult<Solution> {
+    Ok(Solution::from(
+        sum_engine_parts(&schematic.points, &schematic.grid).1,
+    ))
+}
+
+fn sum_engine_parts(points: &Grid<Option<Point>>, grid: &Grid<u8>) -> (u32, u32) {
+    let mut seen: HashSet<(u16, u16)> = HashSet::new();
+    let mut part_number_sum = 0;
+    let mut gear_mult = 0;
+
+    for (_, el) in points.iter() {
+        let Some(el) = el else { continue };
+        if el.point_type == PointType::Symbol || el.point_type == PointType::Star {
+            let mut point_vals = Vec::new();
+            for (x, y) in points.neighbours8(el.x as usize, el.y as usize) {
+                if let Some(num) = extract_number(&mut seen, &points[(x, y)], grid) {
+                    point_vals.push(num);
+                }
+            }
+            if el.point_type == PointType::Star && point_vals.len() == 2 {
+                gear_mult += point_vals[0] * point_vals[1];
+            }
+            part_number_sum += point_vals.iter().sum::<u32>();
+        }
+    }
+    (part_number_sum, gear_mult)
+}
+
+fn extract_number(
+    seen: &mut HashSet<(u16, u16)>,
+    point: &Option<Point>,
+    grid: &Grid<u8>,
+) -> Option<u32> {
+    if let Some(point) = point {
+        if point.point_type == PointType::Number && !seen.contains(&(point.x, point.y)) {
+            let digits = &grid.row(point.y as usize)[point.x as usize..point.x_to as usize];
+            let number = std::str::from_utf8(digits).ok()?.parse::<u32>();
+            seen.insert((point.x, point.y));
+            return number.ok();
+        }
+    }
+    None
+}
+
+fn generate_points(grid: &Grid<u8>) -> Grid<Option<Point>> {
+    let max_x = grid.width();
+    let mut points: Grid<Option<Point>> = Grid::filled(max_x, grid.height(), None);
+
+    for (i, el) in grid.rows().enumerate() {
+        let mut current_idx: Option<u16> = None;
+
+        for (j, &line_char) in el.iter().enumerate() {
+            if line_char.is_ascii_digit() {
+                if current_idx.is_none() {
+                    current_idx = Some(j as u16);
+                }
+            } else {
+                if let Some(idx) = current_idx {
+                    for el in idx..(j as u16) {
+                        points[(el as usize, i)] = Some(Point {
+                            x: idx,
+                            x_to: j as u16,
+                            y: i as u16,
+                            point_type: PointType::Number,
+                        });
+                    }
+                    current_idx = None;
+                }
+                if line_char != b'.' {
+                    let pt = if line_char == b'*' {
+                        PointType::Star
+                    } else {
+                        PointType::Symbol
+                    };
+                    points[(j, i)] = Some(Point {
+                        x: j as u16,
+                        x_to: j as u16,
+                        y: i as u16,
+                        point_type: pt,
+                    });
+                }
+            }
+        }
+
+        // Collect up the remaining numbers
+        if let Some(idx) = current_idx {
+            for el in idx..max_x as u16 {
+                points[(el as usize, i)] = Some(Point {
+                    x: idx,
+                    x_to: max_x as u16,
+                    y: i as u16,
+                    point_type: PointType::Number,
+                });
+            }
+        }
+    }
+    points
+}
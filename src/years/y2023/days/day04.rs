@@ -0,0 +1,72 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    etc::{AocResult, Context},
+    utils::files::parse_lines,
+    Solution,
+};
+
+pub const TITLE: &str = "Scratchcards";
+
+pub fn parse(input: &str) -> AocResult<Vec<HashSet<&str>>> {
+    intersections(input)
+}
+
+pub fn part1(intersections: &[HashSet<&str>]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part1(intersections)))
+}
+
+pub fn part2(intersections: &[HashSet<&str>]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part2(intersections)))
+}
+
+fn solve_part1(intersections: &[HashSet<&str>]) -> i32 {
+    intersections
+        .iter()
+        .filter_map(|res| {
+            if res.is_empty() {
+                return None;
+            }
+            Some(2_i32.pow(res.len() as u32 - 1))
+        })
+        .sum()
+}
+
+fn solve_part2(intersections: &[HashSet<&str>]) -> i32 {
+    let mut scratch_cards: HashMap<usize, i32> = HashMap::new();
+    let mut count_cards = 0;
+    for (idx, el) in intersections.iter().enumerate() {
+        let num_cards = add_card(&mut scratch_cards, idx + 1, 1);
+        for i in idx + 2..idx + 2 + el.len() {
+            add_card(&mut scratch_cards, i, num_cards);
+        }
+        count_cards += num_cards;
+    }
+    count_cards
+}
+
+fn add_card(scratch_cards: &mut HashMap<usize, i32>, game: usize, mult: i32) -> i32 {
+    scratch_cards.entry(game).or_insert(0);
+    match scratch_cards.get_mut(&game) {
+        Some(value) => {
+            *value += mult;
+            *value
+        }
+        None => 0,
+    }
+}
+
+fn intersections(input: &str) -> AocResult<Vec<HashSet<&str>>> {
+    parse_lines(input, |l| {
+        let (_, rhs) = l
+            .split_once(':')
+            .context("Expected a card like \"Card 1: ...\"")?;
+        let (ticket, draw) = rhs
+            .split_once('|')
+            .context("Expected a | between the numbers")?;
+
+        let ticket: HashSet<&str> = HashSet::from_iter(ticket.split_whitespace());
+        let draw: HashSet<&str> = HashSet::from_iter(draw.split_whitespace());
+        Ok(HashSet::from_iter(ticket.intersection(&draw).copied()))
+    })
+}
@@ -0,0 +1,143 @@
+use std::cmp::{max, min};
+
+use crate::{
+    etc::{AocError, AocResult, Context},
+    utils::files::Input,
+    Solution,
+};
+
+pub const TITLE: &str = "If You Give A Seed A Fertilizer";
+
+#[derive(Clone)]
+struct Range {
+    destination_start: i64,
+    source_start: i64,
+    range_length: i64,
+}
+
+#[derive(Clone)]
+struct Block {
+    ranges: Vec<Range>,
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    blocks: Vec<Block>,
+}
+
+pub fn parse(input: &str) -> AocResult<Almanac> {
+    let sections = Input::new(input).blocks();
+    let (seeds, maps) = sections.split_first().context("The almanac is empty")?;
+    let seeds = get_seeds(seeds.raw()).map_err(|e| seeds.locate(e.at_line(1)))?;
+    let blocks = maps.iter().map(generate_block).collect::<AocResult<_>>()?;
+    Ok(Almanac { seeds, blocks })
+}
+
+pub fn part1(almanac: &Almanac) -> AocResult<Solution> {
+    Ok(Solution::from(shortest_distance(&almanac.seeds, &almanac.blocks)))
+}
+
+pub fn part2(almanac: &Almanac) -> AocResult<Solution> {
+    let seeds_p2: Vec<(i64, i64)> = get_seed_ranges(&almanac.seeds);
+    let lowest = solve_part2(&almanac.blocks, &seeds_p2)
+        .ok_or_else(|| AocError::solve("No seeds to plant"))?;
+    Ok(Solution::from(lowest))
+}
+
+fn get_seed_ranges(seeds: &[i64]) -> Vec<(i64, i64)> {
+    let mut ranges = Vec::new();
+    for pair in seeds.windows(2).step_by(2) {
+        if let [a, b] = pair {
+            ranges.push((a.to_owned(), a + b));
+        }
+    }
+    ranges
+}
+
+fn get_seeds(line: &str) -> AocResult<Vec<i64>> {
+    let (_, first_line) = line.split_once(':').context("Expected a line like \"seeds: 1 2 3\"")?;
+
+    first_line
+        .split_whitespace()
+        .map(|s| s.parse::<i64>().context("Invalid seed"))
+        .collect()
+}
+
+fn solve_part2(blocks: &[Block], seed_pairs: &[(i64, i64)]) -> Option<i64> {
+    let mut seeds = seed_pairs.to_vec(); // Clone the initial seed pairs
+    let mut new_seeds;
+    let mut ranges;
+
+    for block in blocks {
+        new_seeds = Vec::new(); // Create a new vector for the next iteration
+        ranges = block.ranges.clone();
+        while let Some((s, e)) = seeds.pop() {
+            let mut no_seeds_pushed = true;
+            for range in &ranges {
+                let os = max(s, range.source_start);
+                let oe = min(e, range.source_start + range.range_length);
+
+                if os < oe {
+                    new_seeds.push((os - range.source_start + range.destination_start, oe - range.source_start + range.destination_start));
+                    if os > s {
+                        seeds.push((s, os));
+                    }
+                    if e > oe {
+                        seeds.push((oe, e));
+                    }
+                    no_seeds_pushed = false;
+                    break;
+                }
+            }
+            if no_seeds_pushed {                
+                new_seeds.push((s, e));
+            }
+        }
+
+        seeds = new_seeds; // Update seeds for the next block
+    }
+    seeds.iter().map(|(first, _)| first).min().copied()
+}
+
+/// Reads the ranges of one map, skipping its `x-to-y map:` heading.
+fn generate_block(map: &Input) -> AocResult<Block> {
+    let ranges = map.skip_lines(1).parse_lines(|line| {
+        let nums = line
+            .split_whitespace()
+            .map(|n| n.parse::<i64>().context("Invalid range number"))
+            .collect::<AocResult<Vec<i64>>>()?;
+        let [destination_start, source_start, range_length] = nums[..] else {
+            return Err(AocError::parse("Expected three numbers in a range"));
+        };
+        Ok(Range {
+            destination_start,
+            source_start,
+            range_length,
+        })
+    })?;
+    Ok(Block { ranges })
+}
+
+fn shortest_distance(seeds: &Vec<i64>, blocks: &Vec<Block>) -> i64 {
+    let mut shortest_distance: i64 = i64::MAX;
+    for seed in seeds {
+        let dist = distance_for_seed(blocks, seed.to_owned());
+        shortest_distance = min(shortest_distance, dist);
+    }
+    shortest_distance
+}
+
+fn distance_for_seed(blocks: &Vec<Block>, seed: i64) -> i64 {
+    let mut id = seed;
+    for block in blocks {
+        let mut new_id = id;
+        for r in &block.ranges {
+            if id >= r.source_start && id < r.source_start + r.range_length {
+                new_id = (id - r.source_start) + r.destination_start;
+                break;
+            }
+        }
+        id = new_id;
+    }
+    id
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+use crate::{
+    etc::{AocError, AocResult, Context},
+    Solution,
+};
+
+pub const TITLE: &str = "Wait For It";
+
+pub fn parse(input: &str) -> AocResult<Vec<&str>> {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.len() < 2 {
+        return Err(AocError::parse(
+            "Expected a line of times and a line of distances",
+        ));
+    }
+    Ok(lines)
+}
+
+pub fn part1(lines: &[&str]) -> AocResult<Solution> {
+    let races = parse_races_pt1(lines)?;
+    let mut mult = 1;
+
+    for race in races {
+        let count = eval_maths(race);
+        mult *= count;
+    }
+    Ok(Solution::from(mult))
+}
+
+pub fn part2(lines: &[&str]) -> AocResult<Solution> {
+    let race2 = parse_races_pt2(lines)?;
+    Ok(Solution::from(eval_maths(race2)))
+}
+
+fn eval_maths(race: (i64, i64)) -> i64 {
+    let mid_point = race.0 as f64 / 2.0;
+
+    let a: f64 = -1_f64;
+    let b: f64 = race.0 as f64;
+    let c: f64 = -race.1 as f64;
+
+    let t_0 = (-b + (b.powf(2.0) - (4.0 * a * c)).sqrt()) / (2.0 * a);
+    let count_above = (mid_point as i64 - (t_0 as i64)) * 2;
+
+    if mid_point.fract() != 0.0 {
+        count_above
+    } else {
+        count_above - 1
+    }
+}
+
+fn parse_races_pt1(lines: &[&str]) -> AocResult<Vec<(i64, i64)>> {
+    let numbers = |line: &str, number: usize| {
+        line.split_whitespace()
+            .skip(1)
+            .map(|n| n.parse::<i64>().context(format!("Could not parse {:?}", n)))
+            .collect::<AocResult<Vec<i64>>>()
+            .map_err(|e| e.at_line(number))
+    };
+    let times = numbers(lines[0], 1)?;
+    let distances = numbers(lines[1], 2)?;
+    if times.len() != distances.len() {
+        return Err(AocError::parse(format!(
+            "Expected {} distances to go with the times, found {}",
+            times.len(),
+            distances.len()
+        ))
+        .at_line(2));
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+fn parse_races_pt2(lines: &[&str]) -> AocResult<(i64, i64)> {
+    let joined_number = |line: &str| {
+        let (_, nums) = line
+            .split_once(':')
+            .context("Expected a label before the numbers")?;
+        let digits: Vec<&str> = nums.split_whitespace().collect();
+        digits.concat().parse::<i64>().context("Invalid number")
+    };
+
+    let time_i = joined_number(lines[0]).map_err(|e| e.at_line(1))?;
+    let dist_i = joined_number(lines[1]).map_err(|e| e.at_line(2))?;
+
+    Ok((time_i, dist_i))
+}
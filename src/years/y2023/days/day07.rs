@@ -3,23 +3,34 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{
+    etc::{AocError, AocResult, Context},
+    utils::files::parse_lines,
+    Solution,
+};
+
+pub const TITLE: &str = "Camel Cards";
 
-struct Hand {
+#[derive(Clone)]
+pub struct Hand<'a> {
     bid: i32,
-    cards: String,
+    cards: &'a str,
+}
+
+pub fn parse(input: &str) -> AocResult<Vec<Hand<'_>>> {
+    parse_hands(input)
+}
+
+pub fn part1(hands: &[Hand]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part(hands, card_rank("AKQJT98765432"), false)))
 }
 
-pub fn solve() -> SolutionPair {
-    let lines = lines_from_file("input/day07.txt");
-    (
-        Solution::from(solve_part(&lines, card_rank("AKQJT98765432"), false)),
-        Solution::from(solve_part(&lines, card_rank("AKQT98765432J"), true)),
-    )
+pub fn part2(hands: &[Hand]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_part(hands, card_rank("AKQT98765432J"), true)))
 }
 
-fn solve_part(lines: &[String], card_rank_pt1: HashMap<char, usize>, j_wildcard: bool) -> i32 {
-    let mut hands: Vec<Hand> = parse_hands(&lines);
+fn solve_part(hands: &[Hand], card_rank_pt1: HashMap<char, usize>, j_wildcard: bool) -> i32 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort_by(|a, b| compare_hands(a, b, &card_rank_pt1, j_wildcard));
     let mut sum = 0;
     for (idx, hand) in hands.iter().enumerate() {
@@ -39,7 +50,7 @@ fn compare_hands(
     if a_type != b_type {
         return a_type.cmp(&b_type);
     }
-    return a
+    a
         .cards
         .chars()
         .zip(b.cards.chars())
@@ -52,7 +63,7 @@ fn compare_hands(
                 _ => None,
             }
         })
-        .unwrap_or(Ordering::Equal);
+        .unwrap_or(Ordering::Equal)
 }
 
 fn card_rank(order: &str) -> HashMap<char, usize> {
@@ -86,7 +97,7 @@ fn reassign_j(card_count: &mut HashMap<char, i32>, target_char: &char) {
         if j_value == 5 {
             return;
         }
-        if let Some(target) = card_count.get_mut(&target_char) {
+        if let Some(target) = card_count.get_mut(target_char) {
             *target += j_value;
         }
         card_count.remove(&'J');
@@ -116,20 +127,25 @@ fn assign_type(card_count: &HashMap<char, i32>) -> u8 {
     }
 }
 
-fn parse_hands(lines: &[String]) -> Vec<Hand> {
-    return lines
-        .iter()
-        .filter_map(|l| {
-            let parts = l.split_whitespace().collect::<Vec<&str>>();
-            if parts.len() == 2 {
-                if let Some(bid) = parts[1].parse::<i32>().ok() {
-                    return Some(Hand {
-                        bid: bid,
-                        cards: parts[0].to_owned(),
-                    });
-                }
-            }
-            None
-        })
-        .collect();
+fn parse_hands(input: &str) -> AocResult<Vec<Hand<'_>>> {
+    parse_lines(input, |line| {
+        let (cards, bid) = line.split_once(' ').context("Expected a hand and a bid")?;
+        if let Some((i, card)) = cards
+            .char_indices()
+            .find(|&(_, card)| !"AKQJT98765432".contains(card))
+        {
+            return Err(AocError::parse(format!("Unknown card: {}", card)).at_column(i + 1));
+        }
+        if cards.len() != 5 {
+            return Err(AocError::parse(format!(
+                "Expected five cards, found {}",
+                cards.len()
+            )));
+        }
+        let bid = bid
+            .trim()
+            .parse()
+            .context(format!("Not a valid bid: {}", bid))?;
+        Ok(Hand { bid, cards })
+    })
 }
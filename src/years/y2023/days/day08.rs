@@ -0,0 +1,116 @@
+use num_integer::gcd;
+use std::collections::HashMap;
+
+use crate::{
+    etc::{AocError, AocResult, Context},
+    Solution,
+};
+
+pub const TITLE: &str = "Haunted Wasteland";
+
+pub struct Network<'a> {
+    instructions: &'a str,
+    step_map: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+pub fn parse(input: &str) -> AocResult<Network<'_>> {
+    let mut lines = input.lines();
+    let instructions = lines.next().context("Must have instructions")?;
+    if instructions.is_empty() || !instructions.chars().all(|c| c == 'L' || c == 'R') {
+        return Err(AocError::parse("Instructions must be a line of L and R").at_line(1));
+    }
+    if lines.next().is_some_and(|line| !line.trim().is_empty()) {
+        return Err(AocError::parse("Expected a blank line after the instructions").at_line(2));
+    }
+    let mut step_map = HashMap::new();
+
+    for (i, line) in lines.enumerate() {
+        if let (Some(key), Some(l), Some(r)) = (line.get(0..3), line.get(7..10), line.get(12..15)) {
+            step_map.insert(key, (l, r));
+        } else {
+            return Err(AocError::parse("Expected a node like \"AAA = (BBB, CCC)\"").at_line(i + 3));
+        }
+    }
+    Ok(Network { instructions, step_map })
+}
+
+pub fn part1(network: &Network) -> AocResult<Solution> {
+    Ok(Solution::from(calculate(
+        &network.step_map,
+        network.instructions,
+        |a| a == "AAA",
+        |b| b == "ZZZ",
+    )?))
+}
+
+pub fn part2(network: &Network) -> AocResult<Solution> {
+    Ok(Solution::from(calculate(
+        &network.step_map,
+        network.instructions,
+        |a| a.ends_with('A'),
+        |b| b.ends_with('Z'),
+    )?))
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    (a * b).abs() / gcd(a, b)
+}
+
+fn lcm_of_list(numbers: &[i64]) -> i64 {
+    numbers.iter().cloned().reduce(lcm).unwrap_or(1)
+}
+
+fn calculate<F, G>(
+    step_map: &HashMap<&str, (&str, &str)>,
+    instructions: &str,
+    start_predicate: F,
+    end_predicate: G,
+) -> AocResult<i64>
+where
+    F: Fn(&str) -> bool,
+    G: Fn(&str) -> bool,
+{
+    let loc_keys: Vec<&str> = step_map
+        .keys()
+        .filter(|p| start_predicate(p))
+        .cloned()
+        .collect();
+
+    if loc_keys.is_empty() {
+        return Err(AocError::solve("No starting node found"));
+    }
+    let steps_max: Vec<i64> = loc_keys
+        .iter()
+        .map(|lk| num_steps(step_map, instructions, lk, |a| end_predicate(a)).map(|s| s as i64))
+        .collect::<AocResult<_>>()?;
+
+    Ok(lcm_of_list(&steps_max))
+}
+
+fn num_steps<F>(
+    step_map: &HashMap<&str, (&str, &str)>,
+    instructions: &str,
+    start_loc: &str,
+    end_predicate: F,
+) -> AocResult<i32>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut iter = instructions.chars().cycle();
+    let mut loc = start_loc;
+    let mut steps = 0;
+    while !end_predicate(loc) {
+        let step = step_map
+            .get(loc)
+            .ok_or_else(|| AocError::solve(format!("No node called {}", loc)))?;
+        steps += 1;
+        if let Some(instr) = iter.next() {
+            if instr == 'L' {
+                loc = step.0;
+            } else {
+                loc = step.1;
+            }
+        }
+    }
+    Ok(steps)
+}
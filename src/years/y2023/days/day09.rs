@@ -0,0 +1,37 @@
+use crate::{
+    etc::{AocResult, Context},
+    utils::files::parse_lines,
+    Solution,
+};
+
+pub const TITLE: &str = "Mirage Maintenance";
+
+pub fn parse(input: &str) -> AocResult<Vec<Vec<i32>>> {
+    parse_lines(input, |line| {
+        line.split_whitespace()
+            .map(|n| n.parse::<i32>().context("Invalid number"))
+            .collect()
+    })
+}
+
+pub fn part1(sequences: &[Vec<i32>]) -> AocResult<Solution> {
+    let sum_tail: i32 = sequences.iter().map(|nums| calculate_recursive(nums).1).sum();
+    Ok(Solution::from(sum_tail))
+}
+
+pub fn part2(sequences: &[Vec<i32>]) -> AocResult<Solution> {
+    let sum_head: i32 = sequences.iter().map(|nums| calculate_recursive(nums).0).sum();
+    Ok(Solution::from(sum_head))
+}
+
+fn calculate_recursive(nums: &[i32]) -> (i32, i32) {
+    if nums.iter().all(|a| a == &0) {
+        return (0, 0);
+    }
+
+    let p1 = calculate_recursive(&nums.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>());
+    (
+        nums.first().unwrap_or(&0) - p1.0,
+        nums.last().unwrap_or(&0) + p1.1,
+    )
+}
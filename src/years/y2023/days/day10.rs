@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::{
+    etc::{AocError, AocResult},
+    utils::grid::Grid,
+    Solution,
+};
+
+pub const TITLE: &str = "Pipe Maze";
+
+#[derive(Debug)]
+struct PipeSection {
+    y_from: i32,
+    y_to: i32,
+    x_from: i32,
+    x_to: i32,
+}
+
+impl PipeSection {
+    fn new(y_from: i32, y_to: i32, x_from: i32, x_to: i32) -> PipeSection {
+        PipeSection {
+            y_from,
+            y_to,
+            x_from,
+            x_to,
+        }
+    }
+
+    fn from_char(value: u8) -> Option<Self> {
+        match value {
+            b'|' => Some(PipeSection::new(-1, 1, 0, 0)),
+            b'-' => Some(PipeSection::new(0, 0, -1, 1)),
+            b'L' => Some(PipeSection::new(-1, 0, 0, 1)),
+            b'J' => Some(PipeSection::new(0, -1, -1, 0)),
+            b'7' => Some(PipeSection::new(1, 0, 0, -1)),
+            b'F' => Some(PipeSection::new(1, 0, 0, 1)),
+            b'S' => Some(PipeSection::new(0, 0, 0, 0)),
+            _ => None,
+        }
+    }
+}
+
+type PipeGrid = Grid<Option<PipeSection>>;
+
+pub struct Maze {
+    tiles: Grid<u8>,
+    main_loop: HashSet<(i32, i32)>,
+    start_goes_north: bool,
+}
+
+pub fn parse(input: &str) -> AocResult<Maze> {
+    let tiles = Grid::parse(input, |c| match c {
+        b'.' => Ok(c),
+        _ if PipeSection::from_char(c).is_some() => Ok(c),
+        _ => Err(AocError::parse(format!("Not a pipe: {:?}", c as char))),
+    })?;
+    let (x, y) = tiles
+        .position(|&c| c == b'S')
+        .ok_or_else(|| AocError::parse("The maze has no starting point S"))?;
+    let start = (x as i32, y as i32);
+    let grid: PipeGrid = tiles.map(|&c| PipeSection::from_char(c));
+    let main_loop = find_main_loop(&grid, start)?;
+    let start_goes_north = connects_back(&grid, start, (0, -1));
+    Ok(Maze {
+        tiles,
+        main_loop,
+        start_goes_north,
+    })
+}
+
+pub fn part1(maze: &Maze) -> AocResult<Solution> {
+    Ok(Solution::from(maze.main_loop.len() as i32 / 2))
+}
+
+pub fn part2(maze: &Maze) -> AocResult<Solution> {
+    let mut captured_points = 0;
+    for (idx, line) in maze.tiles.rows().enumerate() {
+        let mut pipe_count = 0;
+        for (idx2, &ch) in line.iter().enumerate() {
+            let coord = (idx2 as i32, idx as i32);
+            // Count the loop crossings to the left that run north from the row
+            let goes_north = match ch {
+                b'|' | b'L' | b'J' => true,
+                b'S' => maze.start_goes_north,
+                _ => false,
+            };
+            if maze.main_loop.contains(&coord) && goes_north {
+                pipe_count += 1;
+            }
+            if !maze.main_loop.contains(&coord) && pipe_count % 2 != 0 {
+                captured_points += 1;
+            }
+        }
+    }
+    Ok(Solution::from(captured_points))
+}
+
+fn find_main_loop(grid: &PipeGrid, start: (i32, i32)) -> AocResult<HashSet<(i32, i32)>> {
+    let mut main_loop = HashSet::new();
+    let mut previous = start;
+    let mut current = get_pipe_start(grid, (start.0, start.1));
+    // This should be much much cleaner...
+    main_loop.insert(previous);
+    main_loop.insert(current);
+
+    while current != start {
+        let Some(pipe) = get_pipe_element(grid, current) else {
+            return Err(AocError::solve(format!(
+                "The loop from S breaks off at x={}, y={}",
+                current.0, current.1
+            )));
+        };
+        let (x_offset, y_offset) = if previous == (pipe.x_from + current.0, pipe.y_from + current.1)
+        {
+            (pipe.x_to, pipe.y_to)
+        } else {
+            (pipe.x_from, pipe.y_from)
+        };
+
+        previous = current;
+        current = (x_offset + current.0, y_offset + current.1);
+        main_loop.insert(current);
+    }
+    Ok(main_loop)
+}
+
+fn get_pipe_element(grid: &PipeGrid, x_y: (i32, i32)) -> Option<&PipeSection> {
+    grid.get(x_y.0 as i64, x_y.1 as i64)?.as_ref()
+}
+
+fn get_pipe_start(grid: &PipeGrid, start: (i32, i32)) -> (i32, i32) {
+    for tile in [(0, -1), (-1, 0), (1, 0), (0, 1)] {
+        if connects_back(grid, start, tile) {
+            return (start.0 + tile.0, start.1 + tile.1);
+        }
+    }
+    (0, 0)
+}
+
+/// Whether the pipe one `offset` away from `from` has an end pointing back at it.
+fn connects_back(grid: &PipeGrid, from: (i32, i32), offset: (i32, i32)) -> bool {
+    let back = (-offset.0, -offset.1);
+    get_pipe_element(grid, (from.0 + offset.0, from.1 + offset.1))
+        .is_some_and(|pipe| (pipe.x_from, pipe.y_from) == back || (pipe.x_to, pipe.y_to) == back)
+}
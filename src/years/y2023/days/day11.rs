@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use crate::{
+    etc::{AocError, AocResult},
+    utils::grid::Grid,
+    Solution,
+};
+use itertools::Itertools;
+
+pub const TITLE: &str = "Cosmic Expansion";
+
+pub struct Universe {
+    galaxies: Vec<(i64, i64)>,
+    empty_rows: HashSet<i64>,
+    empty_cols: HashSet<i64>,
+}
+
+pub fn parse(input: &str) -> AocResult<Universe> {
+    let image = Grid::parse(input, |c| match c {
+        b'.' | b'#' => Ok(c),
+        _ => Err(AocError::parse("Expected only . and #")),
+    })?;
+    let empty_rows = (0..image.height())
+        .filter(|&row| image.row(row).iter().all(|&c| c == b'.'))
+        .map(|row| row as i64)
+        .collect();
+    let empty_cols = (0..image.width())
+        .filter(|&col| image.column(col).all(|&c| c == b'.'))
+        .map(|col| col as i64)
+        .collect();
+
+    let galaxies = image
+        .iter()
+        .filter(|(_, &c)| c == b'#')
+        .map(|((col, row), _)| (row as i64, col as i64))
+        .collect();
+    Ok(Universe {
+        galaxies,
+        empty_rows,
+        empty_cols,
+    })
+}
+
+pub fn part1(universe: &Universe) -> AocResult<Solution> {
+    Ok(Solution::from(sum_of_distances(universe, 2)))
+}
+
+pub fn part2(universe: &Universe) -> AocResult<Solution> {
+    Ok(Solution::from(sum_of_distances(universe, 1_000_000)))
+}
+
+/// Sums the shortest paths between every pair of galaxies once each empty row
+/// and column has grown to `expansion` times its size.
+pub fn sum_of_distances(universe: &Universe, expansion: i64) -> i64 {
+    distance(
+        &universe.galaxies,
+        &universe.empty_cols,
+        &universe.empty_rows,
+        expansion,
+    )
+}
+
+fn distance(
+    galaxies: &[(i64, i64)],
+    empty_cols: &HashSet<i64>,
+    empty_rows: &HashSet<i64>,
+    explosion_factor: i64,
+) -> i64 {
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(&(row_from, col_from), &(row_to, col_to))| {
+            let mut sum: i64 = 0;
+
+            for empty_row in empty_rows {
+                if empty_row > &row_from.min(row_to) && empty_row < &row_from.max(row_to) {
+                    sum += explosion_factor - 1;
+                }
+            }
+            sum += (row_to - row_from).abs();
+            for empty_col in empty_cols {
+                if empty_col > &col_from.min(col_to) && empty_col < &col_from.max(col_to) {
+                    sum += explosion_factor - 1;
+                }
+            }
+            sum += (col_to - col_from).abs();
+            sum
+        })
+        .sum()
+}
@@ -1,32 +1,49 @@
-use std::{collections::HashMap, iter::repeat};
+use std::collections::HashMap;
 
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{
+    etc::{AocError, AocResult, Context},
+    utils::files::parse_lines,
+    Solution,
+};
 
-pub fn solve() -> SolutionPair {
-    let lines = lines_from_file("input/day12.txt");
-    let sol1 = solve_recursive(&lines, 1);
-    let sol2 = solve_recursive(&lines, 5);
+pub const TITLE: &str = "Hot Springs";
 
-    (Solution::from(sol1), Solution::from(sol2))
+/// A row of springs along with the sizes of its groups of damaged ones.
+type Row<'a> = (&'a str, Vec<i32>);
+
+pub fn parse(input: &str) -> AocResult<Vec<Row<'_>>> {
+    parse_lines(input, |line| {
+        let [springs, groups] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(AocError::parse(
+                "Expected springs and a list of group sizes",
+            ));
+        };
+        let groups = groups
+            .split(',')
+            .map(|size| {
+                size.parse()
+                    .context(format!("Not a valid group size: {:?}", size))
+            })
+            .collect::<AocResult<_>>()?;
+        Ok((springs, groups))
+    })
+}
+
+pub fn part1(rows: &[Row]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_recursive(rows, 1)))
 }
 
-fn solve_recursive(lines: &Vec<String>, multiple: usize) -> i64 {
+pub fn part2(rows: &[Row]) -> AocResult<Solution> {
+    Ok(Solution::from(solve_recursive(rows, 5)))
+}
+
+fn solve_recursive(rows: &[Row], multiple: usize) -> i64 {
     let mut sol = 0;
-    for line in lines {
-        let parts = line.split_whitespace().collect::<Vec<_>>();
-        let springs = repeat(parts[0])
-            .take(multiple)
+    for (springs, groups) in rows {
+        let springs = std::iter::repeat_n(*springs, multiple)
             .collect::<Vec<&str>>()
             .join("?");
-        let damaged = repeat(parts[1])
-            .take(multiple)
-            .collect::<Vec<&str>>()
-            .join(",");
-
-        let contiguous_parts: Vec<i32> = damaged
-            .split(",")
-            .filter_map(|s| s.parse::<i32>().ok())
-            .collect();
+        let contiguous_parts = groups.repeat(multiple);
 
         let mut memo: HashMap<(&[u8], usize), i64> = HashMap::new();
         sol += count(springs.as_bytes(), &contiguous_parts, &mut memo);
@@ -1,52 +1,61 @@
 use std::collections::VecDeque;
 
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{
+    etc::AocResult,
+    utils::{files::Input, grid::Grid},
+    Solution,
+};
 
-pub fn solve() -> SolutionPair {
-    let lines = lines_from_file("input/day12.txt");
-    let grids = lines.iter().fold(vec![vec![]], |mut acc, s| {
-        if s.is_empty() {
-            acc.push(vec![]);
-        } else {
-            acc.last_mut().unwrap().push(s.clone());
-        }
-        acc
-    });
+pub const TITLE: &str = "Point of Incidence";
 
-    let mut sum_pt1 = 0;
-    let mut sum_pt2 = 0;
-    for grid in grids {
-        let transposed = transpose(&grid);
-        sum_pt1 += solve_pt1(&grid, &transposed);
-        sum_pt2 += solve_pt2(&grid, &transposed);
-    }
-    (Solution::from(sum_pt1), Solution::from(sum_pt2))
+pub fn parse(input: &str) -> AocResult<Vec<Grid<u8>>> {
+    Input::new(input)
+        .blocks()
+        .iter()
+        .map(|block| block.grid())
+        .collect()
+}
+
+pub fn part1(grids: &[Grid<u8>]) -> AocResult<Solution> {
+    let sum_pt1: i32 = grids
+        .iter()
+        .map(solve_pt1)
+        .sum();
+    Ok(Solution::from(sum_pt1))
 }
 
-fn solve_pt1(rows: &Vec<String>, cols: &Vec<String>) -> i32 {
-    let row_mirror = find_mirror_location(&rows);
+pub fn part2(grids: &[Grid<u8>]) -> AocResult<Solution> {
+    let sum_pt2: i32 = grids
+        .iter()
+        .map(solve_pt2)
+        .sum();
+    Ok(Solution::from(sum_pt2))
+}
+
+fn solve_pt1(grid: &Grid<u8>) -> i32 {
+    let row_mirror = find_mirror_location(&grid.rows().collect::<Vec<_>>());
     let mut col_mirror = 0;
     if row_mirror == 0 {
-        col_mirror = find_mirror_location(&cols);
+        col_mirror = find_mirror_location(&grid.transpose().rows().collect::<Vec<_>>());
     }
     row_mirror * 100 + col_mirror
 }
 
-fn solve_pt2(rows: &Vec<String>, cols: &Vec<String>) -> i32 {
-    let binary_grid = to_binary_grid(&rows);
+fn solve_pt2(grid: &Grid<u8>) -> i32 {
+    let binary_grid = to_binary_grid(grid);
     let row_index = mutation_index(binary_grid.as_slice());
     if row_index.is_none() {
-        let transposed_binary_grid = to_binary_grid(cols);
+        let transposed_binary_grid = to_binary_grid(&grid.transpose());
         return mutation_index(transposed_binary_grid.as_slice()).unwrap_or(0);
     }
     row_index.unwrap_or(0) * 100
 }
 
-fn to_binary_grid(grid: &Vec<String>) -> Vec<u64> {
-    grid.iter()
+fn to_binary_grid(grid: &Grid<u8>) -> Vec<u64> {
+    grid.rows()
         .map(|s| {
-            s.chars()
-                .fold(0, |acc, c| (acc << 1) | if c == '#' { 1 } else { 0 })
+            s.iter()
+                .fold(0, |acc, c| (acc << 1) | if *c == b'#' { 1 } else { 0 })
         })
         .collect()
 }
@@ -75,20 +84,14 @@ fn mutation_index(slice: &[u64]) -> Option<i32> {
     None
 }
 
-fn transpose(vec: &Vec<String>) -> Vec<String> {
-    (0..vec[0].len())
-        .map(|i| vec.iter().map(|row| row.chars().nth(i).unwrap()).collect())
-        .collect()
-}
-
-fn find_mirror_location(lines: &Vec<String>) -> i32 {
+fn find_mirror_location<T: PartialEq>(lines: &[T]) -> i32 {
     let mut mirror_options: VecDeque<i32> = VecDeque::new();
     let mut el = 0;
 
     while el < lines.len() {
         let line = &lines[el];
         if let Some(mirror_location) = mirror_options.front() {
-            let mirror_check = (2 * *mirror_location as i32) - 1 - el as i32;
+            let mirror_check = (2 * *mirror_location) - 1 - el as i32;
             if mirror_check < 0 {
                 return *mirror_location;
             }
@@ -99,7 +102,7 @@ fn find_mirror_location(lines: &Vec<String>) -> i32 {
                 }
             }
         }
-        if el > 0 && lines.get(el - 1).unwrap_or(&String::default()) == line {
+        if el > 0 && lines.get(el - 1) == Some(line) {
             mirror_options.push_back(el as i32);
         }
         el += 1;
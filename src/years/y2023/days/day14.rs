@@ -0,0 +1,111 @@
+use std::{
+    cmp::min,
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    etc::{AocError, AocResult},
+    utils::grid::Grid,
+    Solution,
+};
+
+pub const TITLE: &str = "Parabolic Reflector Dish";
+
+pub fn parse(input: &str) -> AocResult<Grid<u8>> {
+    let platform = Grid::parse(input, |c| match c {
+        b'O' | b'#' | b'.' => Ok(c),
+        _ => Err(AocError::parse("Expected only O, # and .")),
+    })?;
+    // Tilting north slides each row of the transposed platform to the left
+    Ok(platform.transpose())
+}
+
+pub fn part1(grid: &Grid<u8>) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt1(grid)))
+}
+
+pub fn part2(grid: &Grid<u8>) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt2(grid, 1_000_000_000)))
+}
+
+fn solve_pt1(start_grid: &Grid<u8>) -> i32 {
+    let (weight, _tilted) = tilt_grid(start_grid);
+    weight
+}
+
+fn solve_pt2(start_grid: &Grid<u8>, target: i64) -> i32 {
+    let mut grid = start_grid.clone();
+    let mut rot: i64 = 0;
+    let mut memo: HashMap<u64, i64> = HashMap::new();
+
+    while rot < 4 * target {
+        let hash = grid_hash(&grid);
+
+        if let Some(&previous_rotation) = memo.get(&hash) {
+            let rotation_gap = rot - previous_rotation;
+            let cycles = rotation_gap / 4;
+
+            if cycles > 0 {
+                let remaining_full_cycles = (4 * target - rot) / 4;
+                let skip_cycles = min(remaining_full_cycles / cycles, target / cycles);
+                rot += skip_cycles * cycles * 4;
+            }
+        }
+
+        if rot % 4 == 0 {
+            memo.insert(hash, rot);
+        }
+
+        let (_tilted_weight, grid_state) = tilt_grid(&grid);
+        grid = grid_state.rotate_anticlockwise();
+        rot += 1;
+    }
+    simple_weight(&grid)
+}
+
+fn grid_hash(grid: &Grid<u8>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    grid.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn tilt_grid(grid: &Grid<u8>) -> (i32, Grid<u8>) {
+    let row_length = grid.width() as i32;
+    let mut weight = 0;
+    let mut tilted = Vec::with_capacity(grid.width() * grid.height());
+
+    for row in grid.rows() {
+        let mut new_state = vec![b'.'; row_length as usize];
+        let mut last_block = 0;
+        let mut consecutive_rocks = 0;
+
+        for (chidx, &ch) in row.iter().enumerate() {
+            match ch {
+                b'#' => {
+                    new_state[chidx] = b'#';
+                    last_block = chidx as i32 + 1;
+                    consecutive_rocks = 0;
+                }
+                b'O' => {
+                    new_state[(last_block + consecutive_rocks) as usize] = b'O';
+                    consecutive_rocks += 1;
+                    weight += (row_length + 1) - last_block - consecutive_rocks;
+                }
+                _ => {}
+            }
+        }
+        tilted.extend(new_state);
+    }
+
+    (weight, Grid::from_cells(grid.width(), tilted))
+}
+
+fn simple_weight(grid: &Grid<u8>) -> i32 {
+    let mut weight = 0;
+    for (idx, column) in grid.columns().enumerate() {
+        let s = column.filter(|&&s| s == b'O').count() as i32;
+        weight += s * (grid.width() - idx) as i32;
+    }
+    weight
+}
@@ -1,24 +1,34 @@
 use linked_hash_map::LinkedHashMap;
 use std::str;
 
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{
+    etc::{AocError, AocResult, Context},
+    Solution,
+};
 
-pub fn solve() -> SolutionPair {
-    let line = &lines_from_file("input/day15.txt")[0];
-    let sol1 = solve_pt1(&line);
-    let sol2 = solve_pt2(&line);
+pub const TITLE: &str = "Lens Library";
 
-    (Solution::from(sol1), Solution::from(sol2))
+pub fn parse(input: &str) -> AocResult<&str> {
+    input.lines().next().context("The input is empty")
 }
 
-fn solve_pt1(input: &String) -> i32 {
+pub fn part1(line: &str) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt1(line)))
+}
+
+pub fn part2(line: &str) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt2(line)?))
+}
+
+fn solve_pt1(input: &str) -> i32 {
     input.split(',').map(hash).sum()
 }
 
-fn solve_pt2(line: &String) -> u32 {
+fn solve_pt2(line: &str) -> AocResult<u32> {
     let mut boxes: Vec<LinkedHashMap<String, u32>> = vec![LinkedHashMap::new(); 256];
     for step in line.split(',') {
-        match step.chars().last().expect("Help, invalid input!") {
+        let invalid_step = || AocError::parse(format!("Invalid step: {:?}", step)).at_line(1);
+        match step.chars().last().ok_or_else(invalid_step)? {
             '-' => {
                 let lens_label = &step[0..step.len() - 1];
                 let box_match = hash(lens_label);
@@ -27,10 +37,11 @@ fn solve_pt2(line: &String) -> u32 {
                 }
             }
             digit => {
-                let lens_label = &step[0..step.len() - 2];
+                let lens_label = step.strip_suffix(digit).and_then(|s| s.strip_suffix('='));
+                let lens_label = lens_label.ok_or_else(invalid_step)?;
                 let box_match = hash(lens_label);
 
-                let focal_length = digit.to_digit(10).unwrap();
+                let focal_length = digit.to_digit(10).ok_or_else(invalid_step)?;
                 if let Some(bx) = boxes.get_mut(box_match as usize) {
                     *bx.entry(lens_label.to_owned()).or_insert(0) = focal_length;
                 }
@@ -38,7 +49,7 @@ fn solve_pt2(line: &String) -> u32 {
         }
     }
 
-    boxes
+    Ok(boxes
         .iter()
         .enumerate()
         .map(|(bx_idx, bx)| {
@@ -47,7 +58,7 @@ fn solve_pt2(line: &String) -> u32 {
                 .map(|(idx, v)| (bx_idx as u32 + 1) * (idx as u32 + 1) * v.1)
                 .sum::<u32>()
         })
-        .sum()
+        .sum())
 }
 
 fn hash(input: &str) -> i32 {
@@ -0,0 +1,129 @@
+use std::{cmp::max, collections::HashSet};
+
+use crate::{
+    etc::{AocError, AocResult},
+    utils::{
+        geom::{Dir4, Point2},
+        grid::Grid,
+    },
+    Solution,
+};
+
+pub const TITLE: &str = "The Floor Will Be Lava";
+
+#[derive(Clone)]
+pub struct Tile {
+    energised_count: i32,
+    tile_type: u8,
+    visited: HashSet<Dir4>,
+}
+
+impl Tile {
+    fn accept_beam(&mut self, moving_direction: Dir4) -> Option<Vec<Dir4>> {
+        if self.visited.contains(&moving_direction) {
+            return None;
+        }
+        self.visited.insert(moving_direction);
+
+        self.energised_count += 1;
+        match self.tile_type {
+            b'|' => match moving_direction {
+                Dir4::Up | Dir4::Down => Some(vec![moving_direction]),
+                Dir4::Left | Dir4::Right => Some(vec![Dir4::Up, Dir4::Down]),
+            },
+            b'-' => match moving_direction {
+                Dir4::Left | Dir4::Right => Some(vec![moving_direction]),
+                Dir4::Up | Dir4::Down => Some(vec![Dir4::Left, Dir4::Right]),
+            },
+            b'\\' => match moving_direction {
+                Dir4::Up | Dir4::Down => Some(vec![moving_direction.turn_left()]),
+                Dir4::Left | Dir4::Right => Some(vec![moving_direction.turn_right()]),
+            },
+            b'/' => match moving_direction {
+                Dir4::Up | Dir4::Down => Some(vec![moving_direction.turn_right()]),
+                Dir4::Left | Dir4::Right => Some(vec![moving_direction.turn_left()]),
+            },
+            _ => Some(vec![moving_direction]),
+        }
+    }
+}
+
+impl From<u8> for Tile {
+    fn from(tile_type: u8) -> Self {
+        Tile {
+            energised_count: 0,
+            tile_type,
+            visited: HashSet::new(),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> AocResult<Grid<Tile>> {
+    Grid::parse(input, |ch| match ch {
+        b'.' | b'|' | b'-' | b'\\' | b'/' => Ok(Tile::from(ch)),
+        _ => Err(AocError::parse(format!(
+            "Not a valid tile: {:?}",
+            ch as char
+        ))),
+    })
+}
+
+pub fn part1(tile_grid: &Grid<Tile>) -> AocResult<Solution> {
+    Ok(Solution::from(num_energised_from(
+        Point2::new(0, 0),
+        Dir4::Right,
+        &mut tile_grid.clone(),
+    )))
+}
+
+pub fn part2(tile_grid: &Grid<Tile>) -> AocResult<Solution> {
+    let (width, height) = (tile_grid.width() as i64, tile_grid.height() as i64);
+    let mut sol2 = 0;
+    for i in 0..height {
+        sol2 = max(
+            sol2,
+            num_energised_from(Point2::new(0, i), Dir4::Right, &mut tile_grid.clone()),
+        );
+        sol2 = max(
+            sol2,
+            num_energised_from(
+                Point2::new(width - 1, i),
+                Dir4::Left,
+                &mut tile_grid.clone(),
+            ),
+        );
+    }
+
+    for i in 0..width {
+        sol2 = max(
+            sol2,
+            num_energised_from(Point2::new(i, 0), Dir4::Down, &mut tile_grid.clone()),
+        );
+        sol2 = max(
+            sol2,
+            num_energised_from(Point2::new(i, height - 1), Dir4::Up, &mut tile_grid.clone()),
+        );
+    }
+    Ok(Solution::from(sol2))
+}
+
+fn num_energised_from(
+    location: Point2<i64>,
+    moving_direction: Dir4,
+    tile_grid: &mut Grid<Tile>,
+) -> i32 {
+    let count = match tile_grid.get_mut(location.x, location.y) {
+        None => 0,
+        Some(tile) => match tile.accept_beam(moving_direction) {
+            Some(directions) => {
+                let mut count = if tile.energised_count > 1 { 0 } else { 1 };
+                for dir in directions {
+                    count += num_energised_from(location + dir, dir, tile_grid);
+                }
+                count
+            }
+            None => 0,
+        },
+    };
+    count
+}
@@ -0,0 +1,173 @@
+use crate::{
+    etc::{AocError, AocResult},
+    utils::{
+        geom::{Dir8, Point2},
+        grid::Grid,
+        search::dijkstra,
+    },
+    Solution,
+};
+
+pub const TITLE: &str = "Clumsy Crucible";
+
+pub fn parse(input: &str) -> AocResult<Grid<u8>> {
+    Grid::parse(input, |ch| match ch {
+        b'0'..=b'9' => Ok(ch - b'0'),
+        _ => Err(AocError::parse(format!(
+            "Heat loss must be a digit, not {:?}",
+            ch as char
+        ))),
+    })
+}
+
+pub fn part1(blocks: &Grid<u8>) -> AocResult<Solution> {
+    let route = best_route(blocks, &Rules::new(blocks, 0, 3)).ok_or_else(no_route)?;
+    Ok(Solution::from(route.heat_loss))
+}
+
+pub fn part2(blocks: &Grid<u8>) -> AocResult<Solution> {
+    let route = best_route(blocks, &Rules::new(blocks, 4, 10)).ok_or_else(no_route)?;
+    Ok(Solution::from(route.heat_loss))
+}
+
+fn no_route() -> AocError {
+    AocError::solve("There is no route to the bottom right")
+}
+
+/// How a crucible may move across a map, and where from and to.
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+    pub start: Point2<i64>,
+    pub goal: Point2<i64>,
+    /// The fewest blocks it has to go in a straight line before turning
+    pub min_run: i32,
+    /// The most blocks it can go in a straight line before it has to turn
+    pub max_run: i32,
+    /// Whether it has to have gone `min_run` blocks in a line to stop at the
+    /// goal, too
+    pub stop_after_min_run: bool,
+    /// Whether it can turn straight back the way it came
+    pub reverse: bool,
+    /// Whether it can move diagonally as well as along rows and columns
+    pub diagonal: bool,
+    /// The heat lost moving into a block, given the block's heat loss and the
+    /// way the crucible went. It should never be negative.
+    pub cost: fn(u8, Dir8) -> i32,
+}
+
+impl Rules {
+    /// The puzzle's rules: from the top left to the bottom right of `blocks`,
+    /// turning only left or right and losing each block's heat loss.
+    pub fn new(blocks: &Grid<u8>, min_run: i32, max_run: i32) -> Rules {
+        Rules {
+            start: Point2::new(0, 0),
+            goal: Point2::new(blocks.width() as i64 - 1, blocks.height() as i64 - 1),
+            min_run,
+            max_run,
+            stop_after_min_run: true,
+            reverse: false,
+            diagonal: false,
+            cost: |heat_loss, _| heat_loss as i32,
+        }
+    }
+}
+
+/// The way a crucible takes across the city: each block it moves into, with
+/// the direction it was going, and the heat lost on the way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: i32,
+    pub steps: Vec<(Point2<i64>, Dir8)>,
+}
+
+impl Route {
+    /// The blocks where the crucible changes direction, and the way it leaves
+    /// each.
+    pub fn turns(&self) -> Vec<(Point2<i64>, Dir8)> {
+        self.steps
+            .windows(2)
+            .filter(|pair| pair[0].1 != pair[1].1)
+            .map(|pair| (pair[0].0, pair[1].1))
+            .collect()
+    }
+}
+
+/// Draws `route` over the map of heat losses, marking each block the
+/// crucible moves into with the way it went, like the puzzle text does.
+pub fn route_map(blocks: &Grid<u8>, route: &Route) -> Solution {
+    let mut map = blocks.map(|&loss| (b'0' + loss) as char);
+    for &(location, dir) in &route.steps {
+        if let Some(cell) = map.get_mut(location.x, location.y) {
+            *cell = dir.arrow();
+        }
+    }
+    Solution::from(map.rows().map(<[char]>::to_vec).collect::<Vec<_>>())
+}
+
+/// Where the crucible is, which way it last moved and how many blocks in a
+/// row it has gone that way.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PathState {
+    location: Point2<i64>,
+    direction: Option<Dir8>,
+    cont_moves: i32,
+}
+
+/// The route that loses the least heat under `rules`, if there is one.
+pub fn best_route(blocks: &Grid<u8>, rules: &Rules) -> Option<Route> {
+    if !blocks.contains(rules.start.x, rules.start.y) {
+        return None;
+    }
+    let start = PathState {
+        location: rules.start,
+        direction: None,
+        cont_moves: 0,
+    };
+    let found = dijkstra(
+        start,
+        |st| moves(blocks, rules, st),
+        |st| {
+            st.location == rules.goal
+                && (!rules.stop_after_min_run || st.cont_moves >= rules.min_run)
+        },
+    )?;
+    let steps = found
+        .path
+        .into_iter()
+        .filter_map(|st| Some((st.location, st.direction?)))
+        .collect();
+    Some(Route {
+        heat_loss: found.cost,
+        steps,
+    })
+}
+
+/// The blocks the crucible can move on to next under `rules`, with the heat
+/// lost entering each.
+fn moves(blocks: &Grid<u8>, rules: &Rules, st: &PathState) -> Vec<(PathState, i32)> {
+    Dir8::ALL
+        .into_iter()
+        .filter(|dir| rules.diagonal || dir.is_straight())
+        .filter(|&dir| match st.direction {
+            None => true,
+            Some(last) if dir == last => st.cont_moves < rules.max_run,
+            Some(last) => {
+                (rules.reverse || dir != last.reverse()) && st.cont_moves >= rules.min_run
+            }
+        })
+        .filter_map(|dir| {
+            let location = st.location + dir;
+            let heat_loss = *blocks.get(location.x, location.y)?;
+            let cont_moves = match st.direction {
+                Some(last) if last == dir => st.cont_moves + 1,
+                _ => 1,
+            };
+            let next = PathState {
+                location,
+                direction: Some(dir),
+                cont_moves,
+            };
+            Some((next, (rules.cost)(heat_loss, dir)))
+        })
+        .collect()
+}
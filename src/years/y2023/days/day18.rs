@@ -0,0 +1,109 @@
+use crate::{
+    etc::{AocError, AocResult},
+    utils::{
+        files::parse_lines,
+        geom::{Dir4, Point2},
+    },
+    Solution,
+};
+
+pub const TITLE: &str = "Lavaduct Lagoon";
+
+/// One line of the dig plan, with the instruction hidden in its colour
+/// already decoded.
+pub struct DigStep {
+    dir: Dir4,
+    dist: i64,
+    colour_dir: Dir4,
+    colour_dist: i64,
+}
+
+pub fn parse(input: &str) -> AocResult<Vec<DigStep>> {
+    parse_lines(input, parse_step)
+}
+
+pub fn part1(steps: &[DigStep]) -> AocResult<Solution> {
+    Ok(Solution::from(lagoon_size(
+        steps.iter().map(|s| (s.dir, s.dist)),
+    )))
+}
+
+pub fn part2(steps: &[DigStep]) -> AocResult<Solution> {
+    Ok(Solution::from(lagoon_size(
+        steps.iter().map(|s| (s.colour_dir, s.colour_dist)),
+    )))
+}
+
+fn parse_step(line: &str) -> AocResult<DigStep> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let [letter, dist, colour] = fields[..] else {
+        return Err(AocError::parse("Expected a direction, a distance and a colour"));
+    };
+    let column_of = |field: &str| line.find(field).map_or(1, |c| c + 1);
+
+    let dir = letter
+        .parse::<char>()
+        .ok()
+        .and_then(Dir4::from_letter)
+        .ok_or_else(|| {
+            AocError::parse(format!("Not a valid direction: {}", letter))
+                .at_column(column_of(letter))
+        })?;
+    let dist = dist.parse::<i64>().map_err(|_| {
+        AocError::parse(format!("Not a valid distance: {}", dist)).at_column(column_of(dist))
+    })?;
+
+    let invalid_colour = || {
+        AocError::parse(format!("Invalid hex colour: {}", colour)).at_column(column_of(colour))
+    };
+    let hex = colour
+        .strip_prefix("(#")
+        .and_then(|c| c.strip_suffix(')'))
+        .filter(|h| h.len() == 6 && h.is_ascii())
+        .ok_or_else(invalid_colour)?;
+    let colour_dist = i64::from_str_radix(&hex[..5], 16).map_err(|_| invalid_colour())?;
+    let colour_dir = match &hex[5..] {
+        "0" => Dir4::Right,
+        "1" => Dir4::Down,
+        "2" => Dir4::Left,
+        "3" => Dir4::Up,
+        _ => return Err(invalid_colour()),
+    };
+
+    Ok(DigStep {
+        dir,
+        dist,
+        colour_dir,
+        colour_dist,
+    })
+}
+
+/// Counts the cubes dug out by following `moves` of (direction, distance),
+/// trench included.
+fn lagoon_size(moves: impl Iterator<Item = (Dir4, i64)>) -> i64 {
+    let mut current_location = Point2::new(0, 0);
+    let mut path_length = 0;
+    let mut vertices = Vec::new();
+    for (dir, dist) in moves {
+        let next_location = current_location + dir.delta() * dist;
+        vertices.push(next_location);
+        path_length += next_location.manhattan(current_location);
+        current_location = next_location;
+    }
+
+    let area = shoelace_formula(&vertices);
+    area + (path_length / 2) + 1
+}
+
+fn shoelace_formula(vertices: &[Point2<i64>]) -> i64 {
+    let mut area = 0_i64;
+    let n = vertices.len();
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+        area += vertices[i].x * vertices[j].y;
+        area -= vertices[i].y * vertices[j].x;
+    }
+
+    area.abs() / 2
+}
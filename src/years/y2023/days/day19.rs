@@ -1,39 +1,53 @@
 use std::{
     cmp::{max, min},
     collections::HashMap,
+    str::FromStr,
 };
 
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{
+    etc::{AocError, AocResult, Context},
+    utils::{
+        files::Input,
+        parse::{fields, records},
+    },
+    Solution,
+};
+
+pub const TITLE: &str = "Aplenty";
 
 #[derive(Debug)]
-struct Part {
+pub struct Part {
     x: i64,
     m: i64,
     a: i64,
     s: i64,
 }
 
-impl From<&str> for Part {
-    fn from(input: &str) -> Self {
+impl FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> AocResult<Self> {
         let mut part = Part {
             x: 0,
             m: 0,
             a: 0,
             s: 0,
         };
-        input[1..input.len() - 1].split(',').for_each(|section| {
-            let mut parts = section.split('=');
-            let key = parts.next().unwrap();
-            let val = parts.next().unwrap().parse().expect("Invalid value");
-            match key {
+        let ratings = input
+            .strip_prefix('{')
+            .and_then(|i| i.strip_suffix('}'))
+            .context("Expected a part like {x=1,m=2,a=3,s=4}")?;
+        let ratings: Vec<(String, i64)> = records(ratings, ",", |r| fields(r, "="))?;
+        for (key, val) in ratings {
+            match key.as_str() {
                 "x" => part.x = val,
                 "m" => part.m = val,
                 "a" => part.a = val,
                 "s" => part.s = val,
-                _ => panic!("Unexpected section"),
+                _ => return Err(AocError::parse(format!("Unexpected rating: {}", key))),
             }
-        });
-        part
+        }
+        Ok(part)
     }
 }
 
@@ -51,7 +65,7 @@ impl Rule {
                 'm' => part.m,
                 'a' => part.a,
                 's' => part.s,
-                _ => panic!("Not a valid part type"),
+                _ => unreachable!("Part types are checked when parsing"),
             };
 
             let pass = match pred.1 {
@@ -71,8 +85,8 @@ impl Rule {
     }
 
     fn solve_pt2(&self, constraint: Constraint) -> (Constraint, Constraint) {
-        let mut pc = constraint.clone();
-        let mut fc = constraint.clone();
+        let mut pc = constraint;
+        let mut fc = constraint;
 
         if let Some(p) = self.pred {
             match p.0 {
@@ -89,10 +103,12 @@ impl Rule {
     }
 }
 
-impl From<&str> for Rule {
-    fn from(r: &str) -> Self {
+impl FromStr for Rule {
+    type Err = AocError;
+
+    fn from_str(r: &str) -> AocResult<Self> {
         let parts: Vec<_> = r.split(':').collect();
-        let rule_result = match parts.last().expect("No result found") {
+        let rule_result = match parts.last().context("No result found")? {
             &"R" => RuleResult::Reject,
             &"A" => RuleResult::Accept,
             next => RuleResult::Next(next.to_string()),
@@ -100,15 +116,23 @@ impl From<&str> for Rule {
 
         let pred = if parts.len() == 2 {
             let mut chars = parts[0].chars();
+            let part_type = chars
+                .next()
+                .filter(|c| "xmas".contains(*c))
+                .context("Invalid part type")?;
+            let comparison = chars
+                .next()
+                .filter(|c| *c == '<' || *c == '>')
+                .context("Invalid comparison type")?;
             Some((
-                chars.next().expect("Invalid part type"),
-                chars.next().expect("Invalid comparison type"),
-                parts[0][2..].parse().expect("Invalid threshold"),
+                part_type,
+                comparison,
+                chars.as_str().parse().context("Invalid threshold")?,
             ))
         } else {
             None
         };
-        Rule { rule_result, pred }
+        Ok(Rule { rule_result, pred })
     }
 }
 
@@ -124,30 +148,37 @@ struct WorkFlow {
     rules: Vec<Rule>,
 }
 
-impl From<&str> for WorkFlow {
-    fn from(r: &str) -> Self {
-        let parts: Vec<_> = r.split('{').collect();
-        let name = parts[0].to_string();
-        let rules = parts[1]
-            .trim_end_matches('}')
-            .split(',')
-            .map(Rule::from)
-            .collect();
-
-        WorkFlow { name, rules }
+impl FromStr for WorkFlow {
+    type Err = AocError;
+
+    fn from_str(r: &str) -> AocResult<Self> {
+        let (name, rules) = r
+            .split_once('{')
+            .context("Expected a workflow like px{a<2006:qkq,rfg}")?;
+        let rules = records(rules.trim_end_matches('}'), ",", Rule::from_str)?;
+        if rules.last().is_none_or(|rule| rule.pred.is_some()) {
+            return Err(AocError::parse(
+                "A workflow must end with an unconditional rule",
+            ));
+        }
+
+        Ok(WorkFlow {
+            name: name.to_string(),
+            rules,
+        })
     }
 }
 
 impl WorkFlow {
     fn solve_pt1(&self, part: &Part) -> &RuleResult {
         for rule in &self.rules {
-            if let Some(result) = rule.solve_pt1(&part) {
+            if let Some(result) = rule.solve_pt1(part) {
                 return result;
             } else {
                 continue;
             }
         }
-        panic!("We shouldn't get here");
+        unreachable!("Workflows always end with an unconditional rule");
     }
 
     fn solve_pt2(&self, constraint: Constraint) -> Vec<(RuleResult, Constraint)> {
@@ -163,7 +194,7 @@ impl WorkFlow {
     }
 }
 
-struct WorkFlowRunner {
+pub struct WorkFlowRunner {
     workflows: HashMap<String, WorkFlow>,
 }
 
@@ -189,7 +220,7 @@ impl WorkFlowRunner {
         None
     }
 
-    fn solve_pt2(&self) -> i64 {
+    fn solve_pt2(&self) -> AocResult<i64> {
         let mut search = Vec::new();
         search.push((RuleResult::Next("in".to_string()), Constraint::new()));
 
@@ -202,7 +233,7 @@ impl WorkFlowRunner {
                 RuleResult::Next(next) => {
                     self.workflows
                         .get(&next)
-                        .expect("No workflow found")
+                        .ok_or_else(|| AocError::solve(format!("No workflow called {}", next)))?
                         .solve_pt2(current.1)
                         .iter()
                         .for_each(|ns| search.push(ns.clone()));
@@ -212,7 +243,7 @@ impl WorkFlowRunner {
                 }
             }
         }
-        perm_count
+        Ok(perm_count)
     }
 }
 
@@ -269,19 +300,23 @@ impl Constraint {
     }
 }
 
-pub fn solve() -> SolutionPair {
-    let lines = lines_from_file("input/day19.txt");
-    let split: Vec<Vec<String>> = lines.split(|l| l.is_empty()).map(|s| s.to_vec()).collect();
+pub fn parse(input: &str) -> AocResult<(WorkFlowRunner, Vec<Part>)> {
+    let [workflows, parts, ..] = Input::new(input).blocks()[..] else {
+        return Err(AocError::parse(
+            "Expected workflows and parts separated by a blank line",
+        ));
+    };
 
-    let workflows: Vec<WorkFlow> = split[0]
-        .iter()
-        .map(|s| WorkFlow::from(s.as_str()))
-        .collect();
-    let wf_runner = WorkFlowRunner::new(workflows);
-    let parts: Vec<Part> = split[1].iter().map(|p| Part::from(p.as_str())).collect();
+    let workflows = workflows.parse_lines(WorkFlow::from_str)?;
+    let parts = parts.parse_lines(Part::from_str)?;
+    Ok((WorkFlowRunner::new(workflows), parts))
+}
 
+pub fn part1((wf_runner, parts): &(WorkFlowRunner, Vec<Part>)) -> AocResult<Solution> {
     let sol1: i64 = parts.iter().filter_map(|s| wf_runner.solve_pt1(s)).sum();
-    let sol2: i64 = wf_runner.solve_pt2();
+    Ok(Solution::from(sol1))
+}
 
-    (Solution::from(sol1), Solution::from(sol2))
+pub fn part2((wf_runner, _parts): &(WorkFlowRunner, Vec<Part>)) -> AocResult<Solution> {
+    Ok(Solution::from(wf_runner.solve_pt2()?))
 }
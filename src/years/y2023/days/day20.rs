@@ -1,7 +1,13 @@
 use std::collections::{BTreeSet, HashMap, VecDeque};
 use num_integer::gcd;
 
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{
+    etc::{AocError, AocResult, Context},
+    utils::files::parse_lines,
+    Solution,
+};
+
+pub const TITLE: &str = "Pulse Propagation";
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum ModuleType {
@@ -17,7 +23,7 @@ enum BeamType {
 }
 
 #[derive(Clone, Debug)]
-struct Module {
+pub struct Module {
     name: String,
     on: bool,
     module_type: ModuleType,
@@ -34,8 +40,8 @@ impl Module {
         if self.module_type == ModuleType::Broadcaster {
             return Some((incoming, &self.targets));
         }
-        if self.module_type == ModuleType::FlipFlop {
-            if incoming == BeamType::Low {
+        if self.module_type == ModuleType::FlipFlop
+            && incoming == BeamType::Low {
                 self.on = !self.on;
                 if self.on {
                     return Some((BeamType::High, &self.targets));
@@ -43,7 +49,6 @@ impl Module {
                     return Some((BeamType::Low, &self.targets));
                 }
             }
-        }
         if self.module_type == ModuleType::Conjunction {
             if incoming == BeamType::High {
                 self.receivers.remove(from);
@@ -60,48 +65,52 @@ impl Module {
     }
 }
 
-pub fn solve() -> SolutionPair {
-    let lines = lines_from_file("input/day20.txt");
-    let modules = create_modules(lines);
-    (
-        Solution::from(solve_pt1(&mut modules.clone())),
-        Solution::from(solve_pt2(&mut modules.clone())),
-    )
+pub fn parse(input: &str) -> AocResult<HashMap<String, Module>> {
+    create_modules(input)
 }
 
-fn create_modules(lines: Vec<String>) -> HashMap<String, Module> {
-    let mut modules: HashMap<_, _> = lines
-        .iter()
-        .map(|m| {
-            let sp: Vec<_> = m.split(" -> ").collect();
-            let targets: Vec<String> = sp[1].split(", ").map(|s| s.to_string()).collect();
-
-            match sp[0].chars().next().expect("Not a valid module") {
-                '%' => Module {
-                    name: sp[0][1..].to_owned(),
-                    on: false,
-                    module_type: ModuleType::FlipFlop,
-                    targets,
-                    receivers: BTreeSet::new(),
-                },
-                '&' => Module {
-                    name: sp[0][1..].to_owned(),
-                    on: true,
-                    module_type: ModuleType::Conjunction,
-                    targets,
-                    receivers: BTreeSet::new(),
-                },
-                _ => Module {
-                    name: sp[0].to_owned(),
-                    on: true,
-                    module_type: ModuleType::Broadcaster,
-                    targets,
-                    receivers: BTreeSet::new(),
-                },
-            }
+pub fn part1(modules: &HashMap<String, Module>) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt1(&mut modules.clone())))
+}
+
+pub fn part2(modules: &HashMap<String, Module>) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt2(&mut modules.clone())?))
+}
+
+fn create_modules(input: &str) -> AocResult<HashMap<String, Module>> {
+    let mut modules: HashMap<_, _> = parse_lines(input, |m| {
+        let (name, targets) = m
+            .split_once(" -> ")
+            .context("Expected a module like \"%a -> b, c\"")?;
+        let targets: Vec<String> = targets.split(", ").map(|s| s.to_string()).collect();
+
+        Ok(match name.chars().next().context("Not a valid module")? {
+            '%' => Module {
+                name: name[1..].to_owned(),
+                on: false,
+                module_type: ModuleType::FlipFlop,
+                targets,
+                receivers: BTreeSet::new(),
+            },
+            '&' => Module {
+                name: name[1..].to_owned(),
+                on: true,
+                module_type: ModuleType::Conjunction,
+                targets,
+                receivers: BTreeSet::new(),
+            },
+            _ => Module {
+                name: name.to_owned(),
+                on: true,
+                module_type: ModuleType::Broadcaster,
+                targets,
+                receivers: BTreeSet::new(),
+            },
         })
-        .map(|s| (s.name.to_owned(), s.clone()))
-        .collect();
+    })?
+    .into_iter()
+    .map(|s| (s.name.to_owned(), s))
+    .collect();
 
     modules.insert(
         "rx".to_owned(),
@@ -126,7 +135,7 @@ fn create_modules(lines: Vec<String>) -> HashMap<String, Module> {
             }
         }
     }
-    modules
+    Ok(modules)
 }
 
 fn lcm(a: i64, b: i64) -> i64 {
@@ -159,7 +168,7 @@ fn solve_pt1(modules: &mut HashMap<String, Module>) -> i64 {
                 if let Some(module) = modules.get_mut(&to) {
                     if let Some((new_data, targets)) = module.accept_beam(beam, &from) {
                         for target in targets.iter().cloned() {
-                            state.push_back((to.clone(), target, new_data.clone()));
+                            state.push_back((to.clone(), target, new_data));
                         }
                     }
                 }
@@ -169,7 +178,14 @@ fn solve_pt1(modules: &mut HashMap<String, Module>) -> i64 {
     low * high
 }
 
-fn solve_pt2(modules: &mut HashMap<String, Module>) -> i64 {
+fn solve_pt2(modules: &mut HashMap<String, Module>) -> AocResult<i64> {
+    // rx is fed by a single conjunction, which sends it a low pulse once all
+    // of its own inputs have sent it a high one on the same press
+    let feeder = modules
+        .values()
+        .find(|m| m.targets.iter().any(|t| t == "rx"))
+        .ok_or_else(|| AocError::solve("No module sends pulses to rx"))?;
+    let (feeder, feeder_inputs) = (feeder.name.clone(), feeder.receivers.len());
     let mut map = HashMap::new();
 
     for i in 1..i64::MAX {
@@ -181,21 +197,21 @@ fn solve_pt2(modules: &mut HashMap<String, Module>) -> i64 {
         ));
         while let Some((from, to, beam)) = state.pop_front() {
             {
-                if to == "qn" && beam == BeamType::High {
+                if to == feeder && beam == BeamType::High {
                     map.insert(from.to_owned(), i);
-                    if map.len() == 4 {
-                        return lcm_of_list(map.values().cloned().collect());
+                    if map.len() == feeder_inputs {
+                        return Ok(lcm_of_list(map.values().cloned().collect()));
                     }
                 }
                 if let Some(module) = modules.get_mut(&to) {
                     if let Some((new_beam, targets)) = module.accept_beam(beam, &from) {
                         for target in targets.iter().cloned() {
-                            state.push_back((to.clone(), target, new_beam.clone()));
+                            state.push_back((to.clone(), target, new_beam));
                         }
                     }
                 }
             }
         }
     }
-    0
+    Err(AocError::solve("rx never receives a low pulse"))
 }
\ No newline at end of file
@@ -0,0 +1,192 @@
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
+
+use crate::{
+    etc::{AocError, AocResult, Context},
+    utils::{
+        grid::{Grid, NEIGHBOURS4},
+        search::flood,
+    },
+    Solution,
+};
+
+pub const TITLE: &str = "Step Counter";
+
+pub struct Garden {
+    grid: Grid<u8>,
+    start: (usize, usize),
+    even: Grid<u8>,
+    odd: Grid<u8>,
+}
+
+pub fn parse(input: &str) -> AocResult<Garden> {
+    let grid = Grid::parse(input, Ok)?;
+    if grid.width() != grid.height() {
+        return Err(AocError::parse("The garden must be square"));
+    }
+    let start = grid
+        .position(|&c| c == b'S')
+        .context("Must have a starting point")?;
+
+    // Reachability of a single tile for both step parities, used to tile part 2
+    let (x, y) = (start.0 as i64, start.1 as i64);
+    let steps = grid.width() as i32 - 1;
+    let even = solve_iterative(&grid, (x, y), steps).1;
+    let odd = solve_iterative(&grid, (x - 1, y), steps).1;
+
+    Ok(Garden {
+        grid,
+        start,
+        even,
+        odd,
+    })
+}
+
+pub fn part1(garden: &Garden) -> AocResult<Solution> {
+    Ok(Solution::from(reachable_plots(garden, 64)))
+}
+
+pub fn part2(garden: &Garden) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt2(
+        garden.start.1,
+        26501365,
+        garden.grid.width() as i32,
+        &garden.even,
+        &garden.odd,
+    )))
+}
+
+/// Counts the plots the elf could be standing on after exactly `steps` steps
+/// within the one garden tile.
+pub fn reachable_plots(garden: &Garden, steps: i32) -> i64 {
+    let start = (garden.start.0 as i64, garden.start.1 as i64);
+    solve_iterative(&garden.grid, start, steps).0
+}
+
+fn solve_iterative(grid: &Grid<u8>, start_point: (i64, i64), steps: i32) -> (i64, Grid<u8>) {
+    let is_plot = |(x, y): (i64, i64)| grid.get(x, y).is_some_and(|&c| c != b'#');
+    let taken = if is_plot(start_point) {
+        flood(start_point, steps as usize, |&(x, y)| {
+            NEIGHBOURS4
+                .iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter(|&point| is_plot(point))
+        })
+    } else {
+        HashMap::new()
+    };
+    // The elf can step back and forth, so any plot reached in the right
+    // number of steps to spare is somewhere it can end up
+    let ends_on = |point| {
+        taken
+            .get(&point)
+            .is_some_and(|t| t % 2 == steps as usize % 2)
+    };
+
+    let reached = grid
+        .iter()
+        .map(|((x, y), &ch)| {
+            if ends_on((x as i64, y as i64)) {
+                b'0'
+            } else {
+                ch
+            }
+        })
+        .collect();
+    (
+        taken.keys().filter(|&&point| ends_on(point)).count() as i64,
+        Grid::from_cells(grid.width(), reached),
+    )
+}
+
+fn solve_pt2(start_row: usize, steps: i32, len_i32: i32, odd: &Grid<u8>, even: &Grid<u8>) -> i64 {
+    let row_mid = start_row as i32;
+
+    let mut count: i64 = 0;
+    let mut map = HashMap::new();
+
+    // Start at the pointy end of the diamond and go to the other pointy end
+    for i in -steps..=steps {
+        let row_offset = (row_mid + i) % len_i32;
+        let synth_row = if row_offset < 0 {
+            row_offset + len_i32
+        } else {
+            row_offset
+        };
+
+        let grid_offset = (row_mid + i.abs()) / len_i32;
+        let offset = steps - i.abs();
+        let row_from = row_mid - offset;
+        let row_to = row_mid + offset - 1;
+
+        let flipped = grid_offset % 2 == 0;
+
+        let right_to = min(len_i32 - 1, row_to + 1) + 1;
+        let left_from = max(0, row_from);
+        let num_lengths = (1 + row_to - right_to) / len_i32;
+
+        let use_current = num_lengths / 2;
+        let use_inverse = num_lengths - use_current;
+
+        let even_mult = use_current as i64
+            * 2
+            * get_hits_memo(even, odd, flipped, synth_row, 0, len_i32, &mut map);
+        let odd_mult = use_inverse as i64
+            * 2
+            * get_hits_memo(even, odd, !flipped, synth_row, 0, len_i32, &mut map);
+
+        let rem = 1 + ((1 + row_to - right_to) % len_i32).abs();
+        {
+            let mut right_hits =
+                get_hits_memo(even, odd, flipped, synth_row, row_mid, right_to, &mut map);
+
+            let mut left_hits =
+                get_hits_memo(even, odd, flipped, synth_row, left_from, row_mid, &mut map);
+
+            let use_even_grid = (num_lengths % 2 == 0) ^ flipped;
+            if row_to + 1 >= len_i32 {
+                let remainder_right =
+                    get_hits_memo(even, odd, use_even_grid, synth_row, 0, rem, &mut map);
+                let remainder_left = get_hits_memo(
+                    even,
+                    odd,
+                    use_even_grid,
+                    synth_row,
+                    len_i32 - rem,
+                    len_i32,
+                    &mut map,
+                );
+                right_hits += remainder_right;
+                left_hits += remainder_left;
+            }
+            count += right_hits + left_hits + even_mult + odd_mult
+        }
+    }
+    count
+}
+
+fn get_hits_memo(
+    grid_even: &Grid<u8>,
+    grid_odd: &Grid<u8>,
+    even: bool,
+    row: i32,
+    row_from: i32,
+    row_to: i32,
+    memo: &mut HashMap<(bool, i32, i32, i32), i64>,
+) -> i64 {
+    let memo_key = (even, row, row_from, row_to);
+    if let Some(cached) = memo.get(&memo_key) {
+        return *cached;
+    }
+
+    let grid = if even { grid_even } else { grid_odd };
+    let result = grid.row(row as usize)[row_from as usize..row_to as usize]
+        .iter()
+        .filter(|&&c| c == b'0')
+        .count() as i64;
+
+    memo.insert(memo_key, result);
+    result
+}
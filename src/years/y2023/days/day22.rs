@@ -3,25 +3,28 @@ use std::{
     collections::{BTreeSet, HashMap, HashSet},
 };
 
-use crate::{utils::files::lines_from_file, Solution, SolutionPair};
+use crate::{
+    etc::{AocError, AocResult},
+    utils::{files::parse_lines, geom::Point3, parse::ints_array},
+    Solution,
+};
+
+pub const TITLE: &str = "Sand Slabs";
 
 impl Brick {
     fn collides_with(&self, other_brick: &Brick) -> bool {
-        let (x_start, x_end) = self.x;
-        let (other_x_start, other_x_end) = other_brick.x;
-        if x_end < other_x_start || x_start > other_x_end {
+        let (from, to) = (self.from, self.to);
+        let (other_from, other_to) = (other_brick.from, other_brick.to);
+        if to.x < other_from.x || from.x > other_to.x {
             return false;
         }
-        let (y_start, y_end) = self.y;
-        let (other_y_start, other_y_end) = other_brick.y;
-
-        return y_end >= other_y_start && y_start <= other_y_end;
+        to.y >= other_from.y && from.y <= other_to.y
     }
 }
 
 impl Ord for Brick {
     fn cmp(&self, other: &Self) -> Ordering {
-        match other.z.1.cmp(&self.z.1) {
+        match other.to.z.cmp(&self.to.z) {
             Ordering::Equal => self.id.cmp(&other.id),
             other_ordering => other_ordering,
         }
@@ -34,61 +37,75 @@ impl PartialOrd for Brick {
     }
 }
 
-impl From<(usize, &String)> for Brick {
-    fn from(value: (usize, &String)) -> Self {
-        let components: Vec<&str> = value.1.split("~").collect();
-        let from = components.get(0).expect("No from component found");
-        let to = components.get(1).expect("No to component found");
-        let coords: Vec<(i32, i32)> = from
-            .split(",")
-            .zip(to.split(","))
-            .map(|(f, t)| (f.parse::<i32>().unwrap_or(0), t.parse::<i32>().unwrap_or(0)))
-            .collect();
-
-        Brick {
-            x: *coords.get(0).expect("Must have x co-ordinate"),
-            y: *coords.get(1).expect("Must have y co-ordinate"),
-            z: *coords.get(2).expect("Must have z co-ordinate"),
+impl TryFrom<(usize, &str)> for Brick {
+    type Error = AocError;
+
+    fn try_from(value: (usize, &str)) -> AocResult<Self> {
+        let [x_from, y_from, z_from, x_to, y_to, z_to] = ints_array(value.1)?;
+
+        Ok(Brick {
+            from: Point3::new(x_from, y_from, z_from),
+            to: Point3::new(x_to, y_to, z_to),
             id: value.0,
-        }
+        })
     }
 }
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 struct Brick {
-    x: (i32, i32),
-    y: (i32, i32),
-    z: (i32, i32),
+    from: Point3<i32>,
+    to: Point3<i32>,
     id: usize,
 }
 
-pub fn solve() -> SolutionPair {
-    let lines = lines_from_file("input/day22.txt");
-    let mut bricks: Vec<Brick> = lines.iter().enumerate().map(|l| l.into()).collect();
-    bricks.sort_by_key(|b| b.z.0);
+pub struct Pile {
+    brick_count: usize,
+    load_bearing: HashSet<usize>,
+    sitting: HashMap<usize, HashSet<usize>>,
+    supporting: HashMap<usize, HashSet<usize>>,
+}
+
+pub fn parse(input: &str) -> AocResult<Pile> {
+    let mut id = 0;
+    let mut bricks: Vec<Brick> = parse_lines(input, |l| {
+        id += 1;
+        Brick::try_from((id - 1, l))
+    })?;
+    let brick_count = bricks.len();
+    bricks.sort_by_key(|b| b.from.z);
 
     let (supporting, sitting) = extract_graph(bricks);
     let load_bearing = calculate_load_bearing(&sitting);
 
-    let sol1 = &lines.len() - load_bearing.len();
-    let sol2 = solve_pt2(load_bearing, sitting, supporting);
+    Ok(Pile {
+        brick_count,
+        load_bearing,
+        sitting,
+        supporting,
+    })
+}
 
-    (Solution::from(sol1), Solution::from(sol2))
+pub fn part1(pile: &Pile) -> AocResult<Solution> {
+    Ok(Solution::from(pile.brick_count - pile.load_bearing.len()))
+}
+
+pub fn part2(pile: &Pile) -> AocResult<Solution> {
+    Ok(Solution::from(solve_pt2(&pile.load_bearing, &pile.sitting, &pile.supporting)))
 }
 
 fn solve_pt2(
-    load_bearing: HashSet<usize>,
-    sitting: HashMap<usize, HashSet<usize>>,
-    supporting: HashMap<usize, HashSet<usize>>,
+    load_bearing: &HashSet<usize>,
+    sitting: &HashMap<usize, HashSet<usize>>,
+    supporting: &HashMap<usize, HashSet<usize>>,
 ) -> i64 {
     let default = HashSet::new();
     let mut sol2 = 0;
-    for b in load_bearing {
-        let destroyed = sitting.get(&b).unwrap_or_else(|| &default);
+    for &b in load_bearing {
+        let destroyed = sitting.get(&b).unwrap_or(&default);
         let r = chain_reaction(
             &HashSet::from([b]),
-            &supporting,
-            &sitting,
+            supporting,
+            sitting,
             &mut destroyed.clone(),
         ) - 1;
         sol2 += r;
@@ -117,18 +134,19 @@ fn extract_graph(
     for mut falling in bricks {
         let (z, collisions) = collision_points(falling, &pile);
 
-        let new_z = z + 1;
-        falling.z = (new_z, new_z + (falling.z.1 - falling.z.0));
+        let drop = falling.from.z - (z + 1);
+        falling.from.z -= drop;
+        falling.to.z -= drop;
         pile.insert(falling);
 
         for collision_point in collisions {
             supporting
                 .entry(collision_point.id)
-                .or_insert(HashSet::new())
+                .or_default()
                 .insert(falling.id);
             sitting
                 .entry(falling.id)
-                .or_insert(HashSet::new())
+                .or_default()
                 .insert(collision_point.id);
         }
     }
@@ -142,7 +160,7 @@ fn chain_reaction(
     destroyed: &mut HashSet<usize>,
 ) -> i64 {
     let mut collapsible: Vec<_> = brick_ids.iter().copied().collect();
-    collapsible.retain(|&b| sitting.get(&b).map_or(true, |si| si.is_subset(destroyed)));
+    collapsible.retain(|&b| sitting.get(&b).is_none_or(|si| si.is_subset(destroyed)));
     let destroyable = collapsible.len() as i64;
 
     destroyed.extend(collapsible.iter().copied());
@@ -165,11 +183,11 @@ fn collision_points(falling_brick: Brick, pile: &BTreeSet<Brick>) -> (i32, Vec<B
     let mut collisions = Vec::new();
     let mut z = 0;
     for stable_brick in pile {
-        if stable_brick.z.1 < z {
+        if stable_brick.to.z < z {
             break;
         }
-        if falling_brick.collides_with(&stable_brick) {
-            z = stable_brick.z.1;
+        if falling_brick.collides_with(stable_brick) {
+            z = stable_brick.to.z;
             collisions.push(*stable_brick);
         }
     }
@@ -0,0 +1,197 @@
+use rustworkx_core::petgraph::{
+    algo::has_path_connecting,
+    graph::{NodeIndex, UnGraph},
+    visit::EdgeRef,
+};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::{
+    etc::{AocError, AocResult},
+    utils::{grid::Grid, search::flood},
+    Solution,
+};
+
+pub const TITLE: &str = "A Long Walk";
+
+type PathState = (i32, i32, (i32, i32), Vec<(i32, i32)>);
+type CompressedMap = (UnGraph<(i32, i32), i32>, NodeIndex, NodeIndex);
+
+pub fn parse(input: &str) -> AocResult<Grid<u8>> {
+    let grid = Grid::parse(input, Ok)?;
+    if grid.height() < 2 || grid.width() < 3 {
+        return Err(AocError::parse("The map is too small to walk across"));
+    }
+    Ok(grid)
+}
+
+pub fn part1(input: &Grid<u8>) -> AocResult<Solution> {
+    let (start, target) = endpoints(input);
+    let longest = solve_pt1(start, target, input).ok_or_else(no_path)?;
+    Ok(Solution::from(longest))
+}
+
+pub fn part2(input: &Grid<u8>) -> AocResult<Solution> {
+    let (start, target) = endpoints(input);
+    Ok(Solution::from(solve_pt2(start, target, input)?))
+}
+
+fn no_path() -> AocError {
+    AocError::solve("There is no path from the start to the end")
+}
+
+fn endpoints(input: &Grid<u8>) -> ((i32, i32), (i32, i32)) {
+    let start: (i32, i32) = (1, 0);
+    let target: (i32, i32) = (input.width() as i32 - 2, input.height() as i32 - 1);
+    (start, target)
+}
+
+fn solve_pt2(start: (i32, i32), target: (i32, i32), input: &Grid<u8>) -> AocResult<i32> {
+    let compressed = compress(input, start, target)?;
+
+    let mut sol2 = 0;
+    find_longest_path(
+        &compressed.0,
+        compressed.1,
+        compressed.2,
+        &mut HashSet::new(),
+        &mut Vec::new(),
+        0,
+        &mut sol2,
+    );
+
+    Ok(sol2)
+}
+
+/// Shrinks the map to a graph of its dead ends and forks, the start and end
+/// among them, joined by the lengths of the paths between them.
+fn compress(grid: &Grid<u8>, st: (i32, i32), end: (i32, i32)) -> AocResult<CompressedMap> {
+    let paths_from = |(x, y): (i32, i32)| {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&next| !is_forest(grid, next))
+    };
+    let junctions: HashSet<(i32, i32)> = grid
+        .iter()
+        .map(|((x, y), _)| (x as i32, y as i32))
+        .filter(|&tile| !is_forest(grid, tile) && paths_from(tile).count() != 2)
+        .collect();
+
+    let mut graph = UnGraph::<(i32, i32), i32>::new_undirected();
+    let node_map: HashMap<(i32, i32), NodeIndex> = junctions
+        .iter()
+        .map(|&junction| (junction, graph.add_node(junction)))
+        .collect();
+    for &from in &junctions {
+        // Follow each path out of the junction as far as the next one
+        let distances = flood(from, usize::MAX, |&tile| {
+            let walk_on = tile == from || !junctions.contains(&tile);
+            paths_from(tile).filter(move |_| walk_on)
+        });
+        for (to, distance) in distances {
+            let (from, to) = (node_map[&from], node_map.get(&to).copied());
+            if let Some(to) = to.filter(|&to| to != from && !graph.contains_edge(from, to)) {
+                graph.add_edge(from, to, distance as i32);
+            }
+        }
+    }
+
+    let start = *node_map.get(&st).ok_or_else(no_path)?;
+    let end = *node_map.get(&end).ok_or_else(no_path)?;
+    if !has_path_connecting(&graph, start, end, None) {
+        return Err(no_path());
+    }
+    Ok((graph, start, end))
+}
+
+/// Off the map counts as forest.
+fn is_forest(grid: &Grid<u8>, (x, y): (i32, i32)) -> bool {
+    grid.get(x as i64, y as i64).is_none_or(|&c| c == b'#')
+}
+
+fn find_longest_path(
+    graph: &UnGraph<(i32, i32), i32>,
+    current: NodeIndex,
+    end: NodeIndex,
+    visited: &mut HashSet<NodeIndex>,
+    path: &mut Vec<NodeIndex>,
+    current_length: i32,
+    max_length: &mut i32,
+) {
+    if current == end {
+        if current_length > *max_length {
+            *max_length = current_length;
+        }
+        return;
+    }
+
+    visited.insert(current);
+    path.push(current);
+
+    for edge in graph.edges(current) {
+        let neighbor = edge.target();
+        if !visited.contains(&neighbor) {
+            let edge_weight = *edge.weight();
+            find_longest_path(
+                graph,
+                neighbor,
+                end,
+                visited,
+                path,
+                current_length + edge_weight,
+                max_length,
+            );
+        }
+    }
+
+    path.pop();
+    visited.remove(&current);
+}
+
+fn solve_pt1(start: (i32, i32), target: (i32, i32), input: &Grid<u8>) -> Option<i32> {
+    let mut q = BinaryHeap::<PathState>::new();
+    q.push((0, 0, start, Vec::new()));
+
+    let mut results = HashSet::new();
+    while let Some((_cost, distance, location, journey)) = q.pop() {
+        if journey.contains(&location) {
+            continue;
+        }
+        if location == target {
+            results.insert(distance);
+        }
+        if let Some(&path) = input.get(location.0 as i64, location.1 as i64) {
+            if path == b'#' {
+                continue;
+            }
+            let mut new_journey = Vec::new();
+            new_journey.extend(journey);
+            new_journey.push(location);
+
+            // Try A*?
+            let mut next = Vec::new();
+            match path {
+                b'^' => next.push((location.0, location.1 - 1)),
+                b'>' => next.push((location.0 + 1, location.1)),
+                b'v' => next.push((location.0, location.1 + 1)),
+                b'<' => next.push((location.0 - 1, location.1)),
+                _ => {
+                    next.push((location.0, location.1 - 1));
+                    next.push((location.0 + 1, location.1));
+                    next.push((location.0, location.1 + 1));
+                    next.push((location.0 - 1, location.1));
+                }
+            }
+            for n in next {
+                let manhattan_dist = (n.0 - location.0).abs() + (n.1 - location.1);
+                q.push((
+                    distance + 1 + manhattan_dist,
+                    distance + 1,
+                    n,
+                    new_journey.clone(),
+                ));
+            }
+        }
+    }
+    results.iter().max().copied()
+}
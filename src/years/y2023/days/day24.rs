@@ -0,0 +1,132 @@
+use crate::{
+    etc::{AocError, AocResult},
+    utils::{files::parse_lines, geom::Point3, parse::ints_array},
+    Solution,
+};
+
+pub const TITLE: &str = "Never Tell Me The Odds";
+
+#[derive(Debug, Clone)]
+pub struct HailStone {
+    position: Point3<f64>,
+    velocity: Point3<f64>,
+}
+
+impl TryFrom<&str> for HailStone {
+    type Error = AocError;
+
+    fn try_from(value: &str) -> AocResult<Self> {
+        let [px, py, pz, vx, vy, vz] = ints_array(value)?;
+        Ok(HailStone {
+            position: Point3::new(px, py, pz),
+            velocity: Point3::new(vx, vy, vz),
+        })
+    }
+}
+
+pub fn parse(input: &str) -> AocResult<Vec<HailStone>> {
+    parse_lines(input, HailStone::try_from)
+}
+
+pub fn part1(hail_stones: &[HailStone]) -> AocResult<Solution> {
+    Ok(Solution::from(crossings_within(
+        hail_stones,
+        200000000000000_f64,
+        400000000000000_f64,
+    )))
+}
+
+pub fn part2(_hail_stones: &[HailStone]) -> AocResult<Solution> {
+    Ok(Solution::Unsolved(
+        "needs an SMT solver, see day24::smt_model".to_string(),
+    ))
+}
+
+/// Writes the part 2 system as SMT-LIB for an external solver such as Z3:
+/// a rock thrown from K with velocity V must meet every hailstone i at some
+/// time n_i. The model for the real input is kept in `input/2023/day24_z3.txt`.
+pub fn smt_model(hail_stones: &[HailStone]) -> String {
+    let mut model = String::new();
+    for constant in ["K_x", "K_y", "K_z", "V_x", "V_y", "V_z"] {
+        model += &format!("(declare-const {} Int)\n", constant);
+    }
+    model += "\n";
+    for (x, _s) in hail_stones.iter().enumerate() {
+        model += &format!("(declare-const n_{} Int)\n", x + 1);
+    }
+    model += "\n";
+    for (x, s) in hail_stones.iter().enumerate() {
+        let i = x + 1;
+        model += &format!(
+            "(assert (= (+ {} (* n_{i} {})) (+ K_x (* n_{i} V_x))))\n",
+            s.position.x, s.velocity.x
+        );
+        model += &format!(
+            "(assert (= (+ {} (* n_{i} {})) (+ K_y (* n_{i} V_y))))\n",
+            s.position.y, s.velocity.y
+        );
+        model += &format!(
+            "(assert (= (+ {} (* n_{i} {})) (+ K_z (* n_{i} V_z))))\n",
+            s.position.z, s.velocity.z
+        );
+    }
+    model += "\n(check-sat) (get-model)\n";
+    model
+}
+
+/// Counts the pairs of hailstones whose future paths cross inside the square
+/// test area from `from` to `to`, ignoring the Z axis.
+pub fn crossings_within(hail_stones: &[HailStone], from: f64, to: f64) -> i64 {
+    let mut collisions = 0;
+    for (idx, stone) in hail_stones.iter().enumerate() {
+        for other_hail_stone in hail_stones[idx + 1..].iter() {
+            if collides(stone, other_hail_stone, from, to) {
+                collisions += 1;
+            }
+        }
+    }
+    collisions
+}
+
+fn collides(stone: &HailStone, other_stone: &HailStone, from: f64, to: f64) -> bool {
+    let a_stone = stone.velocity.y / stone.velocity.x;
+    let a_other_stone = other_stone.velocity.y / other_stone.velocity.x;
+
+    let b_stone = stone.position.y - (stone.position.x * a_stone);
+    let b_other_stone = other_stone.position.y - (other_stone.position.x * a_other_stone);
+
+    let x_cross = (b_other_stone - b_stone) / (a_stone - a_other_stone);
+    let y_cross = (a_stone * x_cross) + b_stone;
+
+    if stone.velocity.x >= 0f64 && x_cross < stone.position.x {
+        return false;
+    }
+    if stone.velocity.x <= 0f64 && x_cross > stone.position.x {
+        return false;
+    }
+    if stone.velocity.y >= 0f64 && y_cross < stone.position.y {
+        return false;
+    }
+    if stone.velocity.y <= 0f64 && y_cross > stone.position.y {
+        return false;
+    }
+    if other_stone.velocity.x >= 0f64 && x_cross < other_stone.position.x {
+        return false;
+    }
+    if other_stone.velocity.x <= 0f64 && x_cross > other_stone.position.x {
+        return false;
+    }
+    if other_stone.velocity.y >= 0f64 && y_cross < other_stone.position.y {
+        return false;
+    }
+    if other_stone.velocity.y <= 0f64 && y_cross > other_stone.position.y {
+        return false;
+    }
+    if x_cross < from || x_cross > to {
+        return false;
+    }
+    if y_cross < from || y_cross > to {
+        return false;
+    }
+    true
+}
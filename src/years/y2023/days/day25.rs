@@ -0,0 +1,51 @@
+use rustworkx_core::connectivity::stoer_wagner_min_cut;
+use rustworkx_core::petgraph::graph::UnGraph;
+use rustworkx_core::Result;
+use std::collections::HashMap;
+
+use crate::{
+    etc::{AocError, AocResult, Context},
+    Solution,
+};
+
+pub const TITLE: &str = "Snowverload";
+
+pub fn parse(input: &str) -> AocResult<UnGraph<(), ()>> {
+    let mut graph = UnGraph::<(), ()>::new_undirected();
+    let mut node_map = HashMap::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let (from, to) = line
+            .split_once(": ")
+            .context("Expected a component like \"jqt: rhn xhk\"")
+            .map_err(|e| e.at_line(i + 1))?;
+        let to = to.split_whitespace();
+
+        let from_node = *node_map.entry(from).or_insert_with(|| graph.add_node(()));
+
+        for n in to {
+            let to_node = node_map.entry(n).or_insert_with(|| graph.add_node(()));
+
+            if !graph.contains_edge(from_node, *to_node) {
+                graph.add_edge(from_node, *to_node, ());
+            }
+        }
+    }
+    Ok(graph)
+}
+
+pub fn part1(graph: &UnGraph<(), ()>) -> AocResult<Solution> {
+    let min_cut_res: Result<Option<(usize, Vec<_>)>> = stoer_wagner_min_cut(graph, |_| Ok(1));
+
+    let (_min_cut, partition) = min_cut_res
+        .map_err(|e| AocError::solve(format!("Could not find a minimum cut: {:?}", e)))?
+        .ok_or_else(|| AocError::solve("There must be 2 partitions"))?;
+
+    let sol1 = partition.len() * (graph.node_count() - partition.len());
+    Ok(Solution::from(sol1))
+}
+
+/// Day 25 only has the one puzzle.
+pub fn part2(_graph: &UnGraph<(), ()>) -> AocResult<Solution> {
+    Ok(Solution::None)
+}
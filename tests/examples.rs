@@ -0,0 +1,235 @@
+//! Runs every registered day on the examples from its puzzle text. Each day
+//! keeps them in `examples/YYYY/dayNN/`, next to an `answers.toml` listing the
+//! expected answers for each example, keyed by file name:
+//!
+//! ```toml
+//! [example1]
+//! part1 = "142"
+//! ```
+//!
+//! Examples that need parameters other than the real puzzle's, like a smaller
+//! expansion factor or step count, are checked by the tests at the bottom.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use advent_of_code::{
+    all_days, find_day,
+    utils::{
+        geom::{Dir8, Point2},
+        grid::Grid,
+    },
+    years::y2023::days::*,
+    Part, Solution,
+};
+use serde::Deserialize;
+use toml::Table;
+
+fn example_dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("day{:02}", day))
+}
+
+fn example(year: u16, day: u8, name: &str) -> String {
+    let path = example_dir(year, day).join(format!("{}.txt", name));
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e))
+}
+
+/// Runs each example listed in the day's `answers.toml`, one part at a time so
+/// that an example written for only one part never goes through the other.
+fn check_examples(year: u16, day: u8) {
+    let solver = find_day(year, day).expect("Day is not registered");
+    let answers_path = example_dir(year, day).join("answers.toml");
+    let answers: Table = fs::read_to_string(&answers_path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", answers_path.display(), e))
+        .parse()
+        .expect("Example answers are not valid TOML");
+
+    let mut failures = Vec::new();
+    for (name, parts) in &answers {
+        let input = example(year, day, name);
+        for (key, expected) in parts.as_table().expect("Expected a table per example") {
+            let part = match key.as_str() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                other => panic!("Unknown part in {}: {}", answers_path.display(), other),
+            };
+            let expected = Solution::deserialize(expected.clone())
+                .expect("Example answers must be integers or strings");
+            let answer = solver.solve(&input, Some(part)).map(|output| match part {
+                Part::One => output.part1,
+                Part::Two => output.part2,
+            });
+            match answer {
+                Ok(Some(answer)) if answer == expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{} {}: expected {}, got {:?}",
+                    name, key, expected, answer
+                )),
+                Err(e) => failures.push(format!("{} {}: {}", name, key, e)),
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} day{:02} examples failed:\n{}",
+        year,
+        day,
+        failures.join("\n")
+    );
+}
+
+macro_rules! example_tests {
+    ($year:literal => $($name:ident: $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_examples($year, $day);
+            }
+        )*
+    };
+}
+
+example_tests!(2023 =>
+    day01_examples: 1,
+    day02_examples: 2,
+    day03_examples: 3,
+    day04_examples: 4,
+    day05_examples: 5,
+    day06_examples: 6,
+    day07_examples: 7,
+    day08_examples: 8,
+    day09_examples: 9,
+    day10_examples: 10,
+    day11_examples: 11,
+    day12_examples: 12,
+    day13_examples: 13,
+    day14_examples: 14,
+    day15_examples: 15,
+    day16_examples: 16,
+    day17_examples: 17,
+    day18_examples: 18,
+    day19_examples: 19,
+    day20_examples: 20,
+    day21_examples: 21,
+    day22_examples: 22,
+    day23_examples: 23,
+    day24_examples: 24,
+    day25_examples: 25,
+);
+
+#[test]
+fn every_day_has_examples() {
+    let missing: Vec<String> = all_days()
+        .filter(|d| !example_dir(d.year, d.day).join("answers.toml").exists())
+        .map(|d| format!("{} day{:02}", d.year, d.day))
+        .collect();
+    assert!(missing.is_empty(), "No examples for {}", missing.join(", "));
+}
+
+#[test]
+fn bad_lines_are_reported_where_they_are() {
+    let cases = [
+        (6, "Time: 7 15\nDistance: 9 x\n", "line 2"),
+        (6, "Time: 7 15\nDistance: 9 40 200\n", "line 2"),
+        (7, "32T3K 765\nT55J5\n", "line 2"),
+        (7, "32T3K 765\nT55Z5 684\n", "line 2, column 4"),
+        (8, "LR\nAAA = (BBB, CCC)\n", "line 2"),
+        (12, "#.# 1,1\n??? 1,x\n", "line 2"),
+    ];
+    for (day, input, position) in cases {
+        let error = find_day(2023, day)
+            .unwrap()
+            .solve(input, Some(Part::One))
+            .unwrap_err();
+        assert!(
+            error.to_string().starts_with(&format!("{}:", position)),
+            "day {}: {}",
+            day,
+            error
+        );
+    }
+}
+
+#[test]
+fn day11_expansion_factors() {
+    let universe = day11::parse(&example(2023, 11, "example")).unwrap();
+    assert_eq!(day11::sum_of_distances(&universe, 10), 1030);
+    assert_eq!(day11::sum_of_distances(&universe, 100), 8410);
+}
+
+#[test]
+fn day21_step_counts() {
+    let garden = day21::parse(&example(2023, 21, "example")).unwrap();
+    assert_eq!(day21::reachable_plots(&garden, 6), 16);
+}
+
+#[test]
+fn day24_test_area() {
+    let hail_stones = day24::parse(&example(2023, 24, "example")).unwrap();
+    assert_eq!(day24::crossings_within(&hail_stones, 7.0, 27.0), 2);
+}
+
+#[test]
+fn day17_routes() {
+    let blocks = day17::parse(&example(2023, 17, "example2")).unwrap();
+    let route = day17::best_route(&blocks, &day17::Rules::new(&blocks, 4, 10)).unwrap();
+    assert_eq!(route.heat_loss, 71);
+    assert_eq!(
+        day17::route_map(&blocks, &route).to_string(),
+        "1>>>>>>>1111\n\
+         9999999v9991\n\
+         9999999v9991\n\
+         9999999v9991\n\
+         9999999v>>>>"
+    );
+    assert_eq!(
+        route.turns(),
+        [
+            (Point2::new(7, 0), Dir8::Down),
+            (Point2::new(7, 4), Dir8::Right)
+        ]
+    );
+}
+
+fn heat_loss(blocks: &Grid<u8>, rules: &day17::Rules) -> Option<i32> {
+    day17::best_route(blocks, rules).map(|route| route.heat_loss)
+}
+
+#[test]
+fn day17_rules() {
+    // Stopping short of four blocks in a line lets the ultra crucible cut the
+    // corner it otherwise has to go round
+    let blocks = day17::parse(&example(2023, 17, "example2")).unwrap();
+    let mut rules = day17::Rules::new(&blocks, 4, 10);
+    rules.stop_after_min_run = false;
+    assert_eq!(heat_loss(&blocks, &rules), Some(47));
+
+    // Counting moves instead of heat gives the length of the shortest route
+    let blocks = day17::parse(&example(2023, 17, "example1")).unwrap();
+    let mut rules = day17::Rules::new(&blocks, 0, 3);
+    rules.cost = |_, _| 1;
+    assert_eq!(heat_loss(&blocks, &rules), Some(24));
+    rules.goal = rules.start;
+    rules.start = Point2::new(12, 12);
+    assert_eq!(heat_loss(&blocks, &rules), Some(24));
+
+    let blocks = day17::parse("19\n91\n").unwrap();
+    let mut rules = day17::Rules::new(&blocks, 0, 3);
+    assert_eq!(heat_loss(&blocks, &rules), Some(10));
+    rules.diagonal = true;
+    assert_eq!(heat_loss(&blocks, &rules), Some(1));
+
+    // Along a single row, the only way to arrive after two blocks in a line is
+    // to go past and come back
+    let blocks = day17::parse("1111\n").unwrap();
+    let mut rules = day17::Rules::new(&blocks, 2, 3);
+    rules.goal = Point2::new(1, 0);
+    assert_eq!(heat_loss(&blocks, &rules), None);
+    rules.reverse = true;
+    assert_eq!(heat_loss(&blocks, &rules), Some(5));
+}
@@ -0,0 +1,38 @@
+//! Checks the point arithmetic and the way directions turn.
+
+use advent_of_code::utils::geom::{Dir4, Dir8, Point2, Point3};
+
+#[test]
+fn points_add_up_component_wise() {
+    let a = Point2::new(3_i32, -2);
+    let b = Point2::new(-1, 5);
+    assert_eq!(a + b, Point2::new(2, 3));
+    assert_eq!(a - b, Point2::new(4, -7));
+    assert_eq!(-a * 2, Point2::new(-6, 4));
+    assert_eq!(a.manhattan(b), 11);
+
+    let mut c = Point3::new(1_i64, 2, 3);
+    c += Point3::new(1, 1, 1);
+    assert_eq!(c, Point3::from((2, 3, 4)));
+    assert_eq!(c.manhattan(Point3::default()), 9);
+    assert_eq!(c.to_string(), "(2, 3, 4)");
+}
+
+#[test]
+fn directions_turn_and_step() {
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+    assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+    assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+    assert_eq!(Dir8::UpLeft.reverse(), Dir8::DownRight);
+    assert!(Dir8::from(Dir4::Down).is_straight());
+
+    // Up is towards the first row, as on a grid
+    let origin: Point2<i32> = Point2::new(0, 0);
+    assert_eq!(origin + Dir4::Up, Point2::new(0, -1));
+    assert_eq!(origin + Dir8::DownLeft, Point2::new(-1, 1));
+    for dir in Dir4::ALL {
+        assert_eq!(origin + dir + dir.reverse(), origin);
+        assert_eq!(dir.turn_left().turn_left(), dir.reverse());
+    }
+}
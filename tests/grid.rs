@@ -0,0 +1,68 @@
+//! Checks the shared grid's transforms and edge handling on a grid small enough
+//! to follow by hand.
+
+use advent_of_code::{utils::grid::Grid, AocError};
+
+/// abc
+/// def
+fn letters() -> Grid<u8> {
+    Grid::parse("abc\ndef\n", Ok).unwrap()
+}
+
+#[test]
+fn transforms_move_cells_where_expected() {
+    let grid = letters();
+    assert_eq!(grid.to_string(), "abc\ndef");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+}
+
+#[test]
+fn cells_are_found_by_position() {
+    let grid = letters();
+    assert_eq!(grid[(2, 1)], b'f');
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.get_wrapping(-1, 2), &b'c');
+    assert_eq!(grid.row(1), b"def");
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+    assert_eq!(grid.position(|&c| c == b'e'), Some((1, 1)));
+
+    let mut neighbours: Vec<_> = grid.neighbours4(0, 0).collect();
+    neighbours.sort();
+    assert_eq!(neighbours, [(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours8(1, 0).count(), 5);
+}
+
+#[test]
+fn ragged_and_bad_cells_are_reported_where_they_are() {
+    let error = Grid::parse("ab\nabc\n", Ok).unwrap_err();
+    assert!(matches!(error, AocError::Parse { line: Some(2), .. }), "{}", error);
+
+    let error = Grid::parse("ab\na?\n", |c| match c {
+        b'a' | b'b' => Ok(c),
+        _ => Err(AocError::parse("Not a letter")),
+    })
+    .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            AocError::Parse {
+                line: Some(2),
+                column: Some(2),
+                ..
+            }
+        ),
+        "{}",
+        error
+    );
+}
+
+#[test]
+#[should_panic(expected = "x = 3 is off a grid 3 wide")]
+fn columns_past_the_edge_are_refused() {
+    letters().column(3).count();
+}
@@ -0,0 +1,55 @@
+//! Checks that errors found inside part of an input still point at their line
+//! in the whole file.
+
+use std::{env, fs, process};
+
+use advent_of_code::{
+    utils::files::{read_input, Input},
+    AocError,
+};
+
+/// Two blocks, each a heading over a few numbers, with a bad number on line 7.
+const INPUT: &str = "first:\n1\n2\n\nsecond:\n3\nx\n4\n";
+
+fn line_of(error: AocError) -> Option<usize> {
+    match error {
+        AocError::Parse { line, .. } => line,
+        other => panic!("Expected a parse error, got {}", other),
+    }
+}
+
+#[test]
+fn errors_in_a_later_block_report_their_line_in_the_file() {
+    let path = env::temp_dir().join(format!("aoc-input-{}.txt", process::id()));
+    fs::write(&path, INPUT).unwrap();
+    let buffer = read_input(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let input = Input::new(&buffer);
+    let blocks = input.blocks();
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[1].first_line(), 5);
+
+    let numbers = blocks[1].skip_lines(1);
+    assert_eq!(numbers.first_line(), 6);
+    let error = numbers
+        .parse_lines(|line| {
+            line.parse::<u32>()
+                .map_err(|_| AocError::parse("Not a number"))
+        })
+        .unwrap_err();
+    assert_eq!(line_of(error), Some(7));
+
+    // Errors raised by the solver itself can be moved there too
+    let error = blocks[1].locate(AocError::parse("Bad block").at_line(3));
+    assert_eq!(line_of(error), Some(7));
+    let error = blocks[1].locate(AocError::parse("Bad block"));
+    assert_eq!(line_of(error), Some(5));
+}
+
+#[test]
+fn grids_in_a_later_block_report_their_line_in_the_file() {
+    let input = Input::new("##\n##\n\n..\n...\n");
+    let error = input.blocks()[1].grid().unwrap_err();
+    assert_eq!(line_of(error), Some(5));
+}
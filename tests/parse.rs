@@ -0,0 +1,78 @@
+//! Checks the parsing helpers, including where they say an error is.
+
+use advent_of_code::{
+    utils::parse::{blocks, fields, ints, ints_array, parse_blocks, records},
+    AocError,
+};
+
+/// The line and column a parse error points at.
+fn position(error: AocError) -> (Option<usize>, Option<usize>) {
+    match error {
+        AocError::Parse { line, column, .. } => (line, column),
+        other => panic!("Expected a parse error, got {}", other),
+    }
+}
+
+#[test]
+fn ints_take_their_signs_and_skip_everything_else() {
+    assert_eq!(ints::<i64>("x=-3, y=4 z=10-2").unwrap(), [-3, 4, 10, 2]);
+    assert_eq!(ints::<i64>("-1-3,2-4 a-5").unwrap(), [-1, 3, 2, 4, 5]);
+    assert!(ints::<u32>("no numbers here").unwrap().is_empty());
+    assert_eq!(ints_array::<i32, 3>("1,2,3").unwrap(), [1, 2, 3]);
+
+    // A number that doesn't fit is reported at the column it starts in
+    let error = ints::<u8>("12 300").unwrap_err();
+    assert_eq!(position(error), (None, Some(4)));
+    let error = ints::<u32>("a -5").unwrap_err();
+    assert_eq!(position(error), (None, Some(3)));
+
+    let error = ints_array::<i32, 3>("1,2").unwrap_err();
+    assert!(
+        error.to_string().contains("Expected 3 numbers, found 2"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn fields_and_records_split_on_their_separators() {
+    let (count, colour): (u32, String) = fields("3  blue", " ").unwrap();
+    assert_eq!((count, colour.as_str()), (3, "blue"));
+    let (name, size): (String, i64) = fields("a -> -7", "->").unwrap();
+    assert_eq!((name.as_str(), size), ("a", -7));
+
+    assert!(fields::<(u32, u32)>("1 2 3", " ").is_err());
+    assert!(fields::<(u32, u32)>("1 x", " ").is_err());
+
+    let steps = records("rn=1,cm-,,qp=3\n", ",", |r| Ok(r.len())).unwrap();
+    assert_eq!(steps, [4, 3, 4]);
+}
+
+#[test]
+fn blocks_know_which_line_they_start_on() {
+    let input = "a\nb\n\n\nc\n\nd\ne\n";
+    assert_eq!(blocks(input), [(1, "a\nb"), (5, "c"), (7, "d\ne")]);
+    assert_eq!(blocks("\n\nx"), [(3, "x")]);
+
+    // An error on the second line of the last block is on line 8 of the input
+    let error = parse_blocks(input, |block| {
+        block
+            .lines()
+            .enumerate()
+            .map(|(i, line)| match line {
+                "e" => Err(AocError::parse("Not allowed").at_line(i + 1)),
+                line => Ok(line.len()),
+            })
+            .sum::<Result<usize, _>>()
+    })
+    .unwrap_err();
+    assert_eq!(position(error), (Some(8), None));
+
+    // Errors that don't know their line point at the start of the block
+    let error = parse_blocks(input, |block| match block {
+        "c" => Err(AocError::parse("Not allowed")),
+        _ => Ok(()),
+    })
+    .unwrap_err();
+    assert_eq!(position(error), (Some(5), None));
+}
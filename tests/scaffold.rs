@@ -0,0 +1,101 @@
+//! Runs the day scaffolding over a copy of the files it edits, in a scratch
+//! directory of its own.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use advent_of_code::etc::scaffold::new_day;
+
+const EDITED: [&str; 3] = [
+    "src/years/mod.rs",
+    "src/years/y2023/days/mod.rs",
+    "tests/examples.rs",
+];
+
+fn read(root: &Path, path: &str) -> String {
+    fs::read_to_string(root.join(path)).unwrap()
+}
+
+/// Copies the files the scaffolding edits into a fresh directory, changed by
+/// `edit`.
+fn scratch_tree(name: &str, edit: impl Fn(&str) -> String) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for path in EDITED {
+        fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+        fs::write(root.join(path), edit(&read(Path::new("."), path))).unwrap();
+    }
+    root
+}
+
+#[test]
+fn a_missing_day_is_put_back_in_place() {
+    // Taking day 12 out and adding it back should give the real files again
+    let root = scratch_tree("existing", |text| {
+        text.lines()
+            .filter(|line| !line.contains("=> day12,") && !line.contains("day12_examples"))
+            .map(|line| format!("{}\n", line))
+            .collect()
+    });
+    new_day(&root, 2023, 12, "Hot Springs").unwrap();
+
+    for path in EDITED {
+        assert_eq!(read(&root, path), read(Path::new("."), path), "{}", path);
+    }
+    let module = read(&root, "src/years/y2023/days/day12.rs");
+    assert!(module.contains("pub const TITLE: &str = \"Hot Springs\";"));
+    assert!(root.join("examples/2023/day12/answers.toml").exists());
+    assert!(root.join("examples/2023/day12/example.txt").exists());
+}
+
+#[test]
+fn a_new_year_is_registered() {
+    let root = scratch_tree("new-year", str::to_string);
+    new_day(&root, 2024, 1, "Day 1").unwrap();
+
+    let years = read(&root, "src/years/mod.rs");
+    assert!(years.contains("pub mod y2024;"));
+    assert!(years.contains("&[y2023::days::DAYS, y2024::days::DAYS];"));
+    assert_eq!(
+        read(&root, "src/years/y2024/days/mod.rs"),
+        "crate::register_days! {\n    year: 2024;\n\n     1 => day01,\n}\n"
+    );
+    assert!(read(&root, "tests/examples.rs").ends_with(
+        "mod y2024 {\n    use super::check_examples;\n\n    example_tests!(2024 =>\n        \
+         day01_examples: 1,\n    );\n}\n"
+    ));
+}
+
+#[test]
+fn existing_days_are_left_alone() {
+    let root = scratch_tree("clash", str::to_string);
+    new_day(&root, 2024, 1, "Day 1").unwrap();
+    assert!(new_day(&root, 2024, 1, "Day 1").is_err());
+}
+
+#[test]
+fn titles_are_escaped_in_the_module() {
+    let root = scratch_tree("title", str::to_string);
+    new_day(&root, 2024, 1, r#"Say "Hi" \o/"#).unwrap();
+
+    let module = read(&root, "src/years/y2024/days/day01.rs");
+    assert!(module.contains(r#"pub const TITLE: &str = "Say \"Hi\" \\o/";"#));
+}
+
+#[test]
+fn a_failed_registry_edit_leaves_nothing_behind() {
+    // Without the end of the register_days! block there's nowhere to add a day
+    let root = scratch_tree("broken", |text| text.replace("\n}\n", "\n"));
+    assert!(new_day(&root, 2023, 26, "Day 26").is_err());
+    assert!(!root.join("src/years/y2023/days/day26.rs").exists());
+    assert!(!root.join("examples/2023/day26/answers.toml").exists());
+
+    // So once the registry is fixed, trying again works
+    for path in EDITED {
+        fs::write(root.join(path), read(Path::new("."), path)).unwrap();
+    }
+    new_day(&root, 2023, 26, "Day 26").unwrap();
+}
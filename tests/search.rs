@@ -0,0 +1,47 @@
+//! Runs the shared searches over small graphs whose answers are easy to see.
+
+use advent_of_code::utils::search::{astar, bfs, dijkstra, flood};
+
+/// Steps along a number line, one at a time or doubling.
+fn steps(&n: &i32) -> Vec<i32> {
+    vec![n - 1, n + 1, n * 2]
+}
+
+#[test]
+fn bfs_finds_the_fewest_steps() {
+    let found = bfs(1, steps, |&n| n == 10).unwrap();
+    assert_eq!(found.cost, 4);
+    assert_eq!(found.path, [1, 2, 4, 5, 10]);
+    assert!(bfs(
+        1,
+        |&n: &i32| vec![n + 1].into_iter().filter(|&n| n < 5),
+        |&n| n == 10
+    )
+    .is_none());
+}
+
+#[test]
+fn flood_stops_at_its_limit() {
+    let reached = flood(0, 2, |&n: &i32| vec![n - 1, n + 1]);
+    let mut within: Vec<_> = reached.into_iter().collect();
+    within.sort();
+    assert_eq!(within, [(-2, 2), (-1, 1), (0, 0), (1, 1), (2, 2)]);
+}
+
+#[test]
+fn weighted_searches_find_the_cheapest_path() {
+    // Going straight from a to d costs more than the way round through b and c
+    let edges = |&node: &char| match node {
+        'a' => vec![('b', 1), ('d', 10)],
+        'b' => vec![('c', 2)],
+        'c' => vec![('d', 3)],
+        _ => vec![],
+    };
+    let found = dijkstra('a', edges, |&node| node == 'd').unwrap();
+    assert_eq!(found.cost, 6);
+    assert_eq!(found.path, ['a', 'b', 'c', 'd']);
+
+    let guess = |&node: &char| ('d' as i32 - node as i32).min(1);
+    assert_eq!(astar('a', edges, guess, |&node| node == 'd'), Some(found));
+    assert!(dijkstra('d', edges, |&node| node == 'a').is_none());
+}
@@ -0,0 +1,336 @@
+//! Talks to a stub of the puzzle site listening on a local port, or to a
+//! transport that answers for it, so nothing here touches the real one.
+
+use std::{
+    cell::RefCell,
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{
+    etc::{
+        answers::{Answers, Verdict},
+        site::{
+            fetch_input, submit_answer, Fetched, HttpTransport, Outcome, Response as Reply, Site,
+            Throttle, Transport,
+        },
+    },
+    AocError, AocResult, Part, Solution,
+};
+use tiny_http::{Response, Server};
+
+/// A request the stub saw: its path and cookie.
+type Seen = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+/// Starts a server that answers every request with `respond(path)`, returning
+/// its base URL and a log of what it was asked.
+fn stub(respond: fn(&str) -> (u16, &'static str)) -> (String, Seen) {
+    let server = Server::http("127.0.0.1:0").expect("Could not start the stub server");
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let seen: Seen = Arc::default();
+    let log = Arc::clone(&seen);
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            let (status, body) = respond(request.url());
+            log.lock()
+                .unwrap()
+                .push((request.url().to_string(), cookie));
+            request
+                .respond(Response::from_string(body).with_status_code(status))
+                .unwrap();
+        }
+    });
+    (url, seen)
+}
+
+/// An empty directory of its own for each test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-site-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn site(url: &str, dir: &Path, interval: Duration) -> Site<HttpTransport> {
+    let throttle = Throttle::new(dir.join(".last-request"), interval);
+    let cooldown = Throttle::cooldown(dir.join(".answer-cooldown"));
+    Site::new(HttpTransport::new(), url, "abc123", throttle, cooldown)
+}
+
+fn puzzle_input(path: &str) -> (u16, &'static str) {
+    match path {
+        "/2023/day/5/input" | "/2023/day/6/input" => (200, "seeds: 79 14 55 13\n"),
+        _ => (404, "404 Not Found"),
+    }
+}
+
+#[test]
+fn fetches_an_input_once() {
+    let (url, seen) = stub(puzzle_input);
+    let dir = scratch_dir("once");
+    let site = site(&url, &dir, Duration::ZERO);
+    let path = dir.join("2023").join("day05.txt");
+
+    assert_eq!(
+        fetch_input(&site, 2023, 5, &path).unwrap(),
+        Fetched::Downloaded
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 79 14 55 13\n");
+    assert_eq!(fetch_input(&site, 2023, 5, &path).unwrap(), Fetched::Cached);
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(
+        *seen,
+        [(
+            "/2023/day/5/input".to_string(),
+            Some("session=abc123".to_string())
+        )]
+    );
+}
+
+#[test]
+fn failed_fetches_leave_nothing_behind() {
+    let (url, _) = stub(|_| {
+        (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        )
+    });
+    let dir = scratch_dir("rejected");
+    let site = site(&url, &dir, Duration::ZERO);
+    let path = dir.join("2023").join("day05.txt");
+
+    let error = fetch_input(&site, 2023, 5, &path).unwrap_err();
+    assert!(
+        matches!(error, AocError::Site(ref m) if m.contains("session token")),
+        "{}",
+        error
+    );
+    assert!(!path.exists());
+}
+
+#[test]
+fn requests_are_spaced_out() {
+    let (url, seen) = stub(puzzle_input);
+    let dir = scratch_dir("throttle");
+    let site = site(&url, &dir, Duration::from_millis(300));
+
+    let start = Instant::now();
+    fetch_input(&site, 2023, 5, &dir.join("day05.txt")).unwrap();
+    fetch_input(&site, 2023, 6, &dir.join("day06.txt")).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(seen.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn locked_days_are_not_requested() {
+    let (url, seen) = stub(puzzle_input);
+    let dir = scratch_dir("locked");
+    let site = site(&url, &dir, Duration::ZERO);
+
+    assert!(fetch_input(&site, 2099, 1, &dir.join("day01.txt")).is_err());
+    assert!(seen.lock().unwrap().is_empty());
+}
+
+/// Answers every request with the same page, keeping the forms it was sent.
+struct Canned {
+    page: &'static str,
+    forms: RefCell<Vec<Vec<(String, String)>>>,
+}
+
+impl Transport for Canned {
+    fn get(&self, _: &str, _: &[(&str, &str)]) -> AocResult<Reply> {
+        panic!("Submitting should only post");
+    }
+
+    fn post(&self, _: &str, _: &[(&str, &str)], form: &[(&str, &str)]) -> AocResult<Reply> {
+        let form = form
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        self.forms.borrow_mut().push(form);
+        Ok(Reply {
+            status: 200,
+            body: self.page.to_string(),
+        })
+    }
+}
+
+fn canned(page: &'static str, dir: &Path) -> Site<Canned> {
+    let transport = Canned {
+        page,
+        forms: RefCell::default(),
+    };
+    let throttle = Throttle::new(dir.join(".last-request"), Duration::ZERO);
+    let cooldown = Throttle::cooldown(dir.join(".answer-cooldown"));
+    Site::new(
+        transport,
+        "https://adventofcode.invalid",
+        "abc123",
+        throttle,
+        cooldown,
+    )
+}
+
+const RIGHT: &str = "<main><article><p>That's the right answer!  You are one gold star \
+    closer to restoring snow operations. [<a href=\"/2023\">Return</a>]</p></article></main>";
+
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+    high.  Please wait one minute before trying again. [<a href=\"/2023/day/5\">Return to \
+    Day 5</a>]</p></article></main>";
+
+#[test]
+fn outcomes_are_read_from_the_reply() {
+    assert_eq!(Outcome::parse(RIGHT), Some(Outcome::Correct));
+    assert_eq!(
+        Outcome::parse(TOO_HIGH),
+        Some(Outcome::Incorrect {
+            verdict: Verdict::TooHigh,
+            wait: Some(Duration::from_secs(60)),
+        })
+    );
+    assert_eq!(
+        Outcome::parse(
+            "<article><p>That's not the right answer.  Because you have guessed incorrectly \
+             4 times on this puzzle, please wait 5 minutes before trying again.</p></article>"
+        ),
+        Some(Outcome::Incorrect {
+            verdict: Verdict::Wrong,
+            wait: Some(Duration::from_secs(300)),
+        })
+    );
+    assert_eq!(
+        Outcome::parse(
+            "<article><p>You gave an answer too recently; you have to wait after submitting \
+             an answer before trying again.  You have 1m 38s left to wait.</p></article>"
+        ),
+        Some(Outcome::TooSoon(Duration::from_secs(98)))
+    );
+    // Text it doesn't follow falls back to a minute rather than failing
+    assert_eq!(
+        Outcome::parse(
+            "<article><p>You gave an answer too recently.  You have 1m\u{a0}38s… left to \
+             wait.</p></article>"
+        ),
+        Some(Outcome::TooSoon(Duration::from_secs(60)))
+    );
+    assert_eq!(
+        Outcome::parse(
+            "<article><p>You don't seem to be solving the right level.  Did you already \
+             complete it?</p></article>"
+        ),
+        Some(Outcome::AlreadySolved)
+    );
+    assert_eq!(
+        Outcome::parse("<article><p>Something else</p></article>"),
+        None
+    );
+}
+
+#[test]
+fn right_answers_are_recorded() {
+    let dir = scratch_dir("right");
+    let site = canned(RIGHT, &dir);
+    let mut answers = Answers::default();
+    let answer = Solution::from(46);
+
+    // An answer stored by --record was never checked by the site, so it can
+    // still be sent
+    answers.record(2023, 5, Part::Two, &answer);
+    let outcome = submit_answer(&site, &mut answers, 2023, 5, Part::Two, &answer).unwrap();
+    assert_eq!(outcome, Outcome::Correct);
+    assert_eq!(answers.get(2023, 5, Part::Two), Some(&answer));
+    assert_eq!(
+        *site_forms(&site),
+        [vec![
+            ("level".to_string(), "2".to_string()),
+            ("answer".to_string(), "46".to_string())
+        ]]
+    );
+}
+
+fn site_forms(site: &Site<Canned>) -> Vec<Vec<(String, String)>> {
+    site.transport().forms.borrow().clone()
+}
+
+#[test]
+fn wrong_answers_are_never_sent_again() {
+    let dir = scratch_dir("wrong");
+    let site = canned(TOO_HIGH, &dir);
+    let mut answers = Answers::default();
+
+    let outcome = submit_answer(
+        &site,
+        &mut answers,
+        2023,
+        5,
+        Part::One,
+        &Solution::from(100),
+    );
+    assert!(matches!(outcome, Ok(Outcome::Incorrect { .. })));
+
+    // Saved and loaded again, the verdict still rules out the same answer and
+    // anything higher
+    let path = dir.join("answers.toml");
+    answers.save(&path).unwrap();
+    let mut answers = Answers::load(&path).unwrap();
+    for answer in [100, 150] {
+        let error = submit_answer(
+            &site,
+            &mut answers,
+            2023,
+            5,
+            Part::One,
+            &Solution::from(answer),
+        );
+        assert!(error.is_err());
+    }
+    assert!(answers
+        .ruled_out(2023, 5, Part::One, &Solution::from(99))
+        .is_none());
+
+    // The minute the site asked for holds back the next answer too
+    let error = submit_answer(&site, &mut answers, 2023, 5, Part::One, &Solution::from(99));
+    assert!(matches!(error, Err(AocError::Site(ref m)) if m.contains("wait")));
+    assert_eq!(site_forms(&site).len(), 1);
+}
+
+#[test]
+fn waiting_after_a_wrong_answer_still_allows_fetching() {
+    let (url, seen) = stub(|path| match path {
+        "/2023/day/5/answer" => (200, TOO_HIGH),
+        path => puzzle_input(path),
+    });
+    let dir = scratch_dir("cooldown");
+    let site = site(&url, &dir, Duration::ZERO);
+
+    let outcome = site.submit(2023, 5, Part::One, &Solution::from(100));
+    assert!(matches!(outcome, Ok(Outcome::Incorrect { .. })));
+    fetch_input(&site, 2023, 5, &dir.join("day05.txt")).unwrap();
+    let error = site.submit(2023, 5, Part::One, &Solution::from(50));
+    assert!(matches!(error, Err(AocError::Site(ref m)) if m.contains("wait")));
+    assert_eq!(seen.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn answers_are_posted_to_the_site() {
+    let (url, seen) = stub(|path| match path {
+        "/2023/day/5/answer" => (200, RIGHT),
+        _ => (404, "404 Not Found"),
+    });
+    let dir = scratch_dir("post");
+    let site = site(&url, &dir, Duration::ZERO);
+
+    let outcome = site.submit(2023, 5, Part::One, &Solution::from(35));
+    assert_eq!(outcome.unwrap(), Outcome::Correct);
+    assert_eq!(seen.lock().unwrap().len(), 1);
+}
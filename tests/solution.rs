@@ -0,0 +1,93 @@
+//! Checks that answers compare by value across types and survive being written
+//! out and read back, both as text and through serde.
+
+use std::collections::HashSet;
+
+use advent_of_code::Solution;
+use serde_json::json;
+
+#[test]
+fn integers_compare_by_value_whatever_their_type() {
+    assert_eq!(Solution::I64(5), Solution::U8(5));
+    assert_eq!(Solution::U128(5), Solution::Isize(5));
+    assert_ne!(Solution::I32(-1), Solution::U64(1));
+    assert!(Solution::I8(-3) < Solution::U16(2));
+    assert!(Solution::U128(u128::MAX) > Solution::I128(i128::MAX));
+    assert!(Solution::U64(u64::MAX) < Solution::from("abc"));
+    assert!(Solution::from("abc") < Solution::Unsolved("hard".into()));
+
+    let set: HashSet<_> = [Solution::I64(7), Solution::U32(7), Solution::Usize(7)].into();
+    assert_eq!(set.len(), 1);
+
+    // Text only counts as a number when it's written the way a number would be
+    assert_eq!(Solution::from("42"), Solution::I64(42));
+    assert_ne!(Solution::from("042"), Solution::I64(42));
+    assert_ne!(Solution::from("+42"), Solution::I64(42));
+}
+
+#[test]
+fn answers_read_back_from_their_text() {
+    let parse = |s: &str| s.parse::<Solution>().unwrap();
+    assert!(matches!(parse("-12"), Solution::I64(-12)));
+    assert!(matches!(
+        parse("170141183460469231731687303715884105727"),
+        Solution::I128(i128::MAX)
+    ));
+    assert!(matches!(
+        parse("340282366920938463463374607431768211455"),
+        Solution::U128(u128::MAX)
+    ));
+    assert!(matches!(parse("LRLRL"), Solution::Str(_)));
+    assert!(matches!(parse("#.\n.#"), Solution::Text(_)));
+
+    // Text that happens to be digits stays text
+    for text in ["007", "+7", "-0", " 7"] {
+        let answer = Solution::from(text);
+        let back = parse(&answer.to_string());
+        assert!(matches!(&back, Solution::Str(s) if s == text), "{:?}", back);
+        assert_eq!(back, answer);
+    }
+
+    let grid = Solution::Grid(vec![vec!['#', '.'], vec!['.', '#']]);
+    assert_eq!(parse(&grid.to_string()), grid);
+}
+
+#[test]
+fn serde_round_trips_every_kind_of_answer() {
+    let round_trip = |answer: &Solution| {
+        let value = serde_json::to_value(answer).unwrap();
+        (
+            value.clone(),
+            serde_json::from_value::<Solution>(value).unwrap(),
+        )
+    };
+
+    let (value, back) = round_trip(&Solution::U8(200));
+    assert_eq!(value, json!(200));
+    assert_eq!(back, Solution::U8(200));
+
+    // Beyond 64 bits integers become strings so nothing rounds them
+    let big = Solution::U128(u128::MAX);
+    let (value, back) = round_trip(&big);
+    assert_eq!(value, json!(u128::MAX.to_string()));
+    assert!(matches!(back, Solution::U128(u128::MAX)));
+
+    let (value, back) = round_trip(&Solution::from("007"));
+    assert_eq!(value, json!("007"));
+    assert_eq!(back, Solution::from("007"));
+
+    let grid = Solution::Grid(vec![vec!['a', 'b'], vec!['c', 'd']]);
+    let (value, back) = round_trip(&grid);
+    assert_eq!(value, json!(["ab", "cd"]));
+    assert!(matches!(back, Solution::Grid(_)));
+    assert_eq!(back, grid);
+
+    let unsolved = Solution::Unsolved("needs a solver".into());
+    let (value, back) = round_trip(&unsolved);
+    assert_eq!(value, json!({ "unsolved": "needs a solver" }));
+    assert_eq!(back, unsolved);
+
+    let (value, back) = round_trip(&Solution::None);
+    assert_eq!(value, json!(null));
+    assert!(matches!(back, Solution::None));
+}